@@ -2,7 +2,8 @@
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use ticker_sniffer::{
-    extract_tickers_from_text_with_custom_config, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+    extract_tickers_from_text_with_custom_config, TickerSniffer,
+    DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
 };
 
 fn benchmark_extract_tickers_short(c: &mut Criterion) {
@@ -53,6 +54,22 @@ fn benchmark_extract_tickers_long(c: &mut Criterion) {
     });
 }
 
+fn benchmark_ticker_sniffer_short(c: &mut Criterion) {
+    let text = "AAPL is performing well, but MSFT is also a strong contender. \
+                Amazon is another company making waves in the market.";
+
+    let ticker_sniffer = TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true)
+        .expect("Failed to build TickerSniffer");
+
+    c.bench_function("ticker_sniffer_short", |b| {
+        b.iter(|| {
+            ticker_sniffer
+                .extract(black_box(text))
+                .expect("Ticker extraction failed");
+        })
+    });
+}
+
 fn configure_criterion() -> Criterion {
     Criterion::default()
         .measurement_time(std::time::Duration::from_secs(21))
@@ -62,6 +79,6 @@ fn configure_criterion() -> Criterion {
 criterion_group! {
     name = benches;
     config = configure_criterion();
-    targets = benchmark_extract_tickers_short, benchmark_extract_tickers_long
+    targets = benchmark_extract_tickers_short, benchmark_extract_tickers_long, benchmark_ticker_sniffer_short
 }
 criterion_main!(benches);
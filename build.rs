@@ -1,15 +1,16 @@
 #[path = "dev_shared/lib.rs"]
 mod dev_shared;
-use dev_shared::constants::COMPANY_SYMBOL_CSV_FILE_PATH;
+use dev_shared::constants::COMPANY_SYMBOL_INDEX_FILE_PATH;
 
 use embed_resources::{Resource, ResourceContainer};
+use std::env;
 use std::path::Path;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Ensure that Cargo re-runs the build script if the input file changes
     println!(
         "cargo:rerun-if-changed={:?}",
-        &*COMPANY_SYMBOL_CSV_FILE_PATH
+        &*COMPANY_SYMBOL_INDEX_FILE_PATH
     );
 
     let storage_dir = Path::new("embedded_storage");
@@ -18,15 +19,21 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let mut resource_container = ResourceContainer::new(storage_dir, struct_output_path, struct_name);
 
+    // The symbol index is generated from the company symbol list with
+    // `cargo run -p dev --bin build_symbol_index`, and is embedded uncompressed so
+    // that it can be loaded without decompression, unless the
+    // `compressed-symbol-index` feature trades that for a smaller binary
+    let is_compressed = env::var_os("CARGO_FEATURE_COMPRESSED_SYMBOL_INDEX").is_some();
+
     resource_container.add_resource(
-        "COMPRESSED_COMPANY_SYMBOL_LIST_BYTE_ARRAY",
+        "SYMBOL_INDEX_BYTE_ARRAY",
         Resource::File(
-            COMPANY_SYMBOL_CSV_FILE_PATH
+            COMPANY_SYMBOL_INDEX_FILE_PATH
                 .to_str()
                 .expect("Path contains invalid UTF-8 characters")
                 .to_string(),
         ),
-        true,
+        is_compressed,
     );
 
     resource_container.embed_all()?;
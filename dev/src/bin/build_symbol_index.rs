@@ -0,0 +1,32 @@
+#[path = "../../../dev_shared/lib.rs"]
+mod dev_shared;
+use dev_shared::constants::{COMPANY_SYMBOL_CSV_FILE_PATH, COMPANY_SYMBOL_INDEX_FILE_PATH};
+
+use std::fs;
+use ticker_sniffer::{CompanySymbolListPreprocessor, SymbolIndex};
+
+/// Builds the symbol index which is embedded into the library.
+///
+/// Run from the root of the repository whenever the company symbol list changes:
+///
+/// ```bash
+/// cargo run -p dev --bin build_symbol_index
+/// ```
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let company_symbol_list = CompanySymbolListPreprocessor::read_company_symbol_list_from_path(
+        &*COMPANY_SYMBOL_CSV_FILE_PATH,
+    )?;
+
+    let symbol_index = SymbolIndex::new(company_symbol_list)?;
+    let bytes = symbol_index.to_bytes();
+
+    fs::write(&*COMPANY_SYMBOL_INDEX_FILE_PATH, &bytes)?;
+
+    println!(
+        "Wrote {} bytes to {:?}",
+        bytes.len(),
+        &*COMPANY_SYMBOL_INDEX_FILE_PATH
+    );
+
+    Ok(())
+}
@@ -231,7 +231,7 @@ fn main() {
 
     const IS_CASE_SENSITIVE: bool = true;
 
-    let results = extract_tickers_from_text(&query, IS_CASE_SENSITIVE).unwrap();
+    let results = extract_tickers_from_text(query, IS_CASE_SENSITIVE).unwrap();
 
     println!("Extracted Tickers:");
     for (ticker_symbol, frequency) in results {
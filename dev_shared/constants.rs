@@ -18,3 +18,6 @@ pub static TEST_RUNNER_VALIDATION_FILES_DIRECTORY: LazyLock<PathBuf> =
 
 pub static COMPANY_SYMBOL_CSV_FILE_PATH: LazyLock<PathBuf> =
     LazyLock::new(|| define_path!("data", "company_symbol_list.csv"));
+
+pub static COMPANY_SYMBOL_INDEX_FILE_PATH: LazyLock<PathBuf> =
+    LazyLock::new(|| define_path!("data", "company_symbol_index.bin"));
@@ -1,7 +1,21 @@
-use crate::structs::CompanyTokenProcessorConfig;
+use crate::constants::{
+    CASHTAG_MENTION_CONFIDENCE, EXCHANGE_PREFIXED_MENTION_CONFIDENCE,
+    MIN_TICKER_SYMBOL_UPPERCASE_RATIO, TICKER_SYMBOL_MENTION_CONFIDENCE,
+};
+use crate::structs::{CompanyTokenProcessorConfig, StopWordPreset, StopWords};
 
 pub const DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG: &CompanyTokenProcessorConfig =
     &CompanyTokenProcessorConfig {
         threshold_ratio_exact_matches: 0.50,
         threshold_min_company_token_coverage: 0.60,
+        min_ticker_symbol_uppercase_ratio: MIN_TICKER_SYMBOL_UPPERCASE_RATIO,
+        ticker_symbol_mention_confidence: TICKER_SYMBOL_MENTION_CONFIDENCE,
+        cashtag_mention_confidence: CASHTAG_MENTION_CONFIDENCE,
+        exchange_prefixed_mention_confidence: EXCHANGE_PREFIXED_MENTION_CONFIDENCE,
+        included_asset_types: None,
+        excluded_asset_types: Vec::new(),
+        included_markets: None,
+        as_of_date: None,
+        stop_words: StopWords::new(StopWordPreset::English),
+        fold_diacritics: false,
     };
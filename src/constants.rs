@@ -1,3 +1,4 @@
+/// English stop words, used by `StopWordPreset::English`.
 pub const STOP_WORDS: &[&str] = &[
     "a",
     "about",
@@ -175,3 +176,58 @@ pub const STOP_WORDS: &[&str] = &[
     "yourself",
     "yourselves",
 ];
+
+/// French stop words, used by `StopWordPreset::French`.
+pub const FRENCH_STOP_WORDS: &[&str] = &[
+    "à", "au", "aux", "avec", "ce", "ces", "cet", "cette", "dans", "de", "des", "du", "elle",
+    "elles", "en", "et", "eux", "il", "ils", "je", "la", "le", "les", "leur", "leurs", "lui", "ma",
+    "mais", "me", "même", "mes", "moi", "mon", "ne", "nos", "notre", "nous", "on", "ou", "où",
+    "par", "pas", "pour", "qu", "que", "qui", "sa", "se", "ses", "son", "sont", "sur", "ta", "te",
+    "tes", "toi", "ton", "tu", "un", "une", "vos", "votre", "vous", "y", "été", "être", "est",
+    "était", "étaient", "avoir", "avait", "ont", "a",
+];
+
+/// German stop words, used by `StopWordPreset::German`.
+pub const GERMAN_STOP_WORDS: &[&str] = &[
+    "aber", "alle", "allem", "allen", "aller", "alles", "als", "also", "am", "an", "andere",
+    "anderen", "anderer", "anderes", "auch", "auf", "aus", "bei", "bin", "bis", "bist", "da",
+    "damit", "dann", "das", "dass", "dem", "den", "der", "des", "die", "dies", "diese", "diesem",
+    "diesen", "dieser", "dieses", "doch", "dort", "du", "durch", "ein", "eine", "einem", "einen",
+    "einer", "eines", "er", "es", "etwas", "euch", "euer", "eure", "für", "gegen", "gewesen",
+    "habe", "haben", "hat", "hatte", "hatten", "hier", "hin", "ich", "ihm", "ihn", "ihnen", "ihr",
+    "ihre", "im", "in", "ins", "ist", "jede", "jedem", "jeden", "jeder", "jedes", "kein", "keine",
+    "man", "mein", "meine", "mich", "mir", "mit", "muss", "nach", "nicht", "nichts", "noch", "nun",
+    "nur", "ob", "oder", "ohne", "sehr", "sein", "seine", "sich", "sie", "sind", "so", "soll",
+    "sollte", "sondern", "über", "um", "und", "uns", "unser", "unter", "viel", "vom", "von", "vor",
+    "war", "waren", "was", "weil", "welche", "wenn", "werde", "werden", "wie", "wieder", "wir",
+    "wird", "wo", "zu", "zum", "zur", "zwischen",
+];
+
+/// Spanish stop words, used by `StopWordPreset::Spanish`.
+pub const SPANISH_STOP_WORDS: &[&str] = &[
+    "a", "al", "algo", "algunas", "algunos", "ante", "antes", "como", "con", "contra", "cual",
+    "cuando", "de", "del", "desde", "donde", "durante", "e", "el", "él", "ella", "ellas", "ellos",
+    "en", "entre", "era", "es", "esa", "esas", "ese", "eso", "esos", "esta", "estas", "este",
+    "esto", "estos", "fue", "ha", "han", "hasta", "hay", "la", "las", "le", "les", "lo", "los",
+    "más", "me", "mi", "mí", "mis", "mucho", "muchos", "muy", "nada", "ni", "no", "nos",
+    "nosotros", "o", "os", "otra", "otras", "otro", "otros", "para", "pero", "poco", "por",
+    "porque", "que", "qué", "quien", "quienes", "se", "ser", "si", "sí", "sin", "sobre", "son",
+    "su", "sus", "también", "te", "ti", "todo", "todos", "tu", "tú", "tus", "un", "una", "uno",
+    "unos", "y", "ya", "yo",
+];
+
+/// The default confidence assigned to a bare ticker symbol mention (e.g., "AAPL")
+/// which survived the exact match ratio filter.
+pub const TICKER_SYMBOL_MENTION_CONFIDENCE: f32 = 0.5;
+
+/// The default confidence assigned to a cashtag mention (e.g., "$AAPL"), which
+/// explicitly references a ticker symbol.
+pub const CASHTAG_MENTION_CONFIDENCE: f32 = 0.95;
+
+/// The default confidence assigned to an exchange-prefixed ticker symbol mention
+/// (e.g., "NASDAQ: AAPL"), which explicitly references a listing.
+pub const EXCHANGE_PREFIXED_MENTION_CONFIDENCE: f32 = 0.99;
+
+/// The default minimum ratio of uppercase letters a token must have to be
+/// considered a ticker symbol, when filtering with case sensitivity.
+pub const MIN_TICKER_SYMBOL_UPPERCASE_RATIO: f32 = 0.9;
@@ -6,18 +6,25 @@ pub use config::DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG;
 pub mod constants;
 pub mod structs;
 mod utils;
+#[cfg(feature = "csv")]
+pub use structs::CompanySymbolListPreprocessor;
 pub use structs::{
-    CompanySymbolListPreprocessor, CompanyTokenMapper, CompanyTokenProcessor,
-    CompanyTokenProcessorConfig, Error, TokenMapper, TokenParityState, TokenRangeState, Tokenizer,
+    AssetType, CompanyMetadata, CompanyNameConsistencyIssue, CompanyNameConsistencyIssueKind,
+    CompanySymbolListIssue, CompanySymbolListIssueKind, CompanySymbolListOverlay,
+    CompanySymbolListPatch, CompanySymbolListValidator, CompanyTokenMapper, CompanyTokenProcessor,
+    CompanyTokenProcessorConfig, CompanyTokenProcessorConfigBuilder, Date, Error, ExtractionTrace,
+    Market, MarkupFormat, MarkupText, StopWordPreset, StopWords, SymbolIndex, TickerSniffer,
+    TickerSymbolMention, TickerSymbolMentionKind, TickerSymbolResult, TickerSymbolTrace,
+    TickerSymbolTraceOutcome, TokenMapper, TokenParityState, TokenRangeState,
+    TokenSequenceCollision, TokenSequenceCollisionMatch, TokenSpan, Tokenizer,
 };
-pub use utils::sort_results;
+pub use utils::{normalize_ticker_symbol, sort_results};
 pub mod types;
+use std::sync::OnceLock;
 pub use types::{
     AlternateCompanyName, CompanyName, CompanySymbolList, TickerSymbol, TickerSymbolFrequencyMap,
     Token, TokenId, TokenRef, TokenVector,
 };
-use structs::ResourceContainer;
-
 
 /// Extracts ticker symbols from the provided text using the default configuration.
 ///
@@ -48,17 +55,15 @@ pub fn extract_tickers_from_text(
         return Ok(TickerSymbolFrequencyMap::new());
     }
 
-    let results_ticker_symbol_frequency_map = extract_tickers_from_text_with_custom_config(
-        DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
-        text,
-        is_case_sensitive,
-    )?;
-
-    Ok(results_ticker_symbol_frequency_map)
+    get_default_ticker_sniffer(is_case_sensitive)?.extract(text)
 }
 
 /// Extracts ticker symbols from the provided text using a custom configuration.
 ///
+/// Note: The symbol index is rebuilt on each call. When processing multiple
+/// documents with the same configuration, create a `TickerSniffer` once and
+/// reuse it instead.
+///
 /// # Arguments
 /// * `document_token_processor_config` - A reference to the custom configuration
 ///   for processing tokens.
@@ -86,19 +91,245 @@ pub fn extract_tickers_from_text_with_custom_config(
     text: &str,
     is_case_sensitive: bool,
 ) -> Result<TickerSymbolFrequencyMap, Error> {
-    // Load the company symbol list
-    let company_symbol_list =
-        CompanySymbolListPreprocessor::extract_company_symbol_list_from_bytes(
-            ResourceContainer::COMPRESSED_COMPANY_SYMBOL_LIST_BYTE_ARRAY,
-        )?;
+    let ticker_sniffer = TickerSniffer::new(document_token_processor_config, is_case_sensitive)?;
+
+    ticker_sniffer.extract(text)
+}
+
+/// Extracts the individual ticker symbol mentions from the provided text using the
+/// default configuration.
+///
+/// Each mention includes the byte range and text of the match in the original
+/// document, which makes it suitable for highlighting or linking mentions.
+///
+/// # Arguments
+/// * `text` - A reference to the input text document from which ticker symbol
+///   mentions are to be extracted.
+/// * `is_case_sensitive` - Whether or not the text document should be filtered using case sensitivity.
+///
+/// # Returns
+/// * `Ok(Vec<TickerSymbolMention>)` - The mentions, ordered by their position in
+///   the text, if the operation is successful.
+/// * `Err(Error)` - An error if processing fails.
+///
+/// # Example
+/// ```
+/// use ticker_sniffer::{extract_ticker_mentions_from_text, TickerSymbolMentionKind};
+///
+/// let text = "Shares of Apple rose.";
+/// let mentions = extract_ticker_mentions_from_text(text, true).unwrap();
+///
+/// assert_eq!(mentions[0].ticker_symbol, "AAPL");
+/// assert_eq!(mentions[0].byte_range, 10..15);
+/// assert_eq!(mentions[0].text, "Apple");
+/// assert_eq!(mentions[0].kind, TickerSymbolMentionKind::CompanyName);
+/// ```
+pub fn extract_ticker_mentions_from_text(
+    text: &str,
+    is_case_sensitive: bool,
+) -> Result<Vec<TickerSymbolMention>, Error> {
+    // Skip entirely if there is no text
+    if text.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    get_default_ticker_sniffer(is_case_sensitive)?.extract_mentions(text)
+}
+
+/// Extracts the individual ticker symbol mentions from the provided text using a
+/// custom configuration.
+///
+/// Note: The symbol index is rebuilt on each call. When processing multiple
+/// documents with the same configuration, create a `TickerSniffer` once and
+/// reuse it instead.
+///
+/// # Arguments
+/// * `document_token_processor_config` - A reference to the custom configuration
+///   for processing tokens.
+/// * `text` - A reference to the input text document from which ticker symbol
+///   mentions are to be extracted.
+/// * `is_case_sensitive` - Whether or not the text document should be filtered using case sensitivity.
+///
+/// # Returns
+/// * `Ok(Vec<TickerSymbolMention>)` - The mentions, ordered by their position in
+///   the text, if the operation is successful.
+/// * `Err(Error)` - An error if processing fails.
+pub fn extract_ticker_mentions_from_text_with_custom_config(
+    document_token_processor_config: &CompanyTokenProcessorConfig,
+    text: &str,
+    is_case_sensitive: bool,
+) -> Result<Vec<TickerSymbolMention>, Error> {
+    let ticker_sniffer = TickerSniffer::new(document_token_processor_config, is_case_sensitive)?;
+
+    ticker_sniffer.extract_mentions(text)
+}
+
+/// Extracts ticker symbols from the provided text using the default configuration,
+/// along with their frequencies and confidence scores.
+///
+/// # Arguments
+/// * `text` - A reference to the input text document from which ticker symbols
+///   are to be extracted.
+/// * `is_case_sensitive` - Whether or not the text document should be filtered using case sensitivity.
+///
+/// # Returns
+/// * `Ok(Vec<TickerSymbolResult>)` - The results, ordered by confidence, if the
+///   operation is successful.
+/// * `Err(Error)` - An error if processing fails.
+///
+/// # Example
+/// ```
+/// use ticker_sniffer::extract_ticker_results_from_text;
+///
+/// let results = extract_ticker_results_from_text("Apple Inc. (AAPL) and Apple", true).unwrap();
+///
+/// assert_eq!(results[0].ticker_symbol, "AAPL");
+/// assert!(results[0].confidence > 0.0 && results[0].confidence <= 1.0);
+/// ```
+pub fn extract_ticker_results_from_text(
+    text: &str,
+    is_case_sensitive: bool,
+) -> Result<Vec<TickerSymbolResult>, Error> {
+    // Skip entirely if there is no text
+    if text.is_empty() {
+        return Ok(Vec::new());
+    }
 
-    let company_token_processor = CompanyTokenProcessor::new(
-        document_token_processor_config,
-        &company_symbol_list,
-        is_case_sensitive,
-    );
+    get_default_ticker_sniffer(is_case_sensitive)?.extract_results(text)
+}
+
+/// Extracts ticker symbols from the provided text using a custom configuration,
+/// along with their frequencies and confidence scores.
+///
+/// Note: The symbol index is rebuilt on each call. When processing multiple
+/// documents with the same configuration, create a `TickerSniffer` once and
+/// reuse it instead.
+///
+/// # Arguments
+/// * `document_token_processor_config` - A reference to the custom configuration
+///   for processing tokens.
+/// * `text` - A reference to the input text document from which ticker symbols
+///   are to be extracted.
+/// * `is_case_sensitive` - Whether or not the text document should be filtered using case sensitivity.
+///
+/// # Returns
+/// * `Ok(Vec<TickerSymbolResult>)` - The results, ordered by confidence, if the
+///   operation is successful.
+/// * `Err(Error)` - An error if processing fails.
+pub fn extract_ticker_results_from_text_with_custom_config(
+    document_token_processor_config: &CompanyTokenProcessorConfig,
+    text: &str,
+    is_case_sensitive: bool,
+) -> Result<Vec<TickerSymbolResult>, Error> {
+    let ticker_sniffer = TickerSniffer::new(document_token_processor_config, is_case_sensitive)?;
+
+    ticker_sniffer.extract_results(text)
+}
+
+/// Extracts ticker symbols from each of the provided text documents using the
+/// default configuration.
+///
+/// With the `rayon` feature enabled, documents are processed in parallel.
+///
+/// # Arguments
+/// * `texts` - The text documents from which ticker symbols are to be extracted.
+/// * `is_case_sensitive` - Whether or not the text documents should be filtered using case sensitivity.
+///
+/// # Returns
+/// * `Ok(Vec<Result<TickerSymbolFrequencyMap, Error>>)` - A result for each text
+///   document, in input order.
+/// * `Err(Error)` - An error if the symbol index cannot be built.
+///
+/// # Example
+/// ```
+/// use ticker_sniffer::extract_tickers_from_texts;
+///
+/// let results = extract_tickers_from_texts(["Apple rose", "Microsoft fell"], true).unwrap();
+/// assert_eq!(results.len(), 2);
+/// ```
+pub fn extract_tickers_from_texts<I>(
+    texts: I,
+    is_case_sensitive: bool,
+) -> Result<Vec<Result<TickerSymbolFrequencyMap, Error>>, Error>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str> + Send,
+{
+    Ok(get_default_ticker_sniffer(is_case_sensitive)?.extract_batch(texts))
+}
+
+/// Retrieves the company name and alternate names for the given ticker symbol from
+/// the embedded company symbol list.
+///
+/// # Arguments
+/// * `ticker_symbol` - The ticker symbol to look up. The lookup is case insensitive.
+///
+/// # Returns
+/// * `Ok(Some(&CompanyMetadata))` - The company metadata, if the ticker symbol is known.
+/// * `Ok(None)` - If the ticker symbol is not in the company symbol list.
+/// * `Err(Error)` - An error if the company symbol list cannot be loaded.
+///
+/// # Example
+/// ```
+/// use ticker_sniffer::get_company_metadata;
+///
+/// let company_metadata = get_company_metadata("AAPL").unwrap().unwrap();
+/// assert_eq!(company_metadata.company_name.as_deref(), Some("Apple Inc."));
+/// ```
+pub fn get_company_metadata(
+    ticker_symbol: &str,
+) -> Result<Option<&'static CompanyMetadata>, Error> {
+    Ok(get_default_ticker_sniffer(true)?.get_company_metadata(ticker_symbol))
+}
+
+/// Searches the embedded company symbol list for companies whose name or
+/// alternate names contain the query, best match first.
+///
+/// # Arguments
+/// * `query` - The text to search for. The search is case insensitive.
+///
+/// # Returns
+/// * `Ok(Vec<&CompanyMetadata>)` - The matching company metadata.
+/// * `Err(Error)` - An error if the company symbol list cannot be loaded.
+///
+/// # Example
+/// ```
+/// use ticker_sniffer::search_company_metadata;
+///
+/// let results = search_company_metadata("Berkshire Hathaway").unwrap();
+/// assert!(results.iter().any(|company_metadata| company_metadata.ticker_symbol == "BRK-B"));
+/// ```
+pub fn search_company_metadata(query: &str) -> Result<Vec<&'static CompanyMetadata>, Error> {
+    Ok(get_default_ticker_sniffer(true)?.search_company_metadata(query))
+}
+
+/// Retrieves the shared `TickerSniffer` instance for the default configuration,
+/// building it on first use.
+///
+/// Separate instances are kept for case sensitive and case insensitive parsing.
+/// If multiple threads race to build the same instance, only one is retained.
+///
+/// # Arguments
+/// * `is_case_sensitive` - Whether or not text documents should be filtered using case sensitivity.
+///
+/// # Errors
+/// Returns an error if the instance cannot be built.
+fn get_default_ticker_sniffer(is_case_sensitive: bool) -> Result<&'static TickerSniffer, Error> {
+    static CASE_SENSITIVE_TICKER_SNIFFER: OnceLock<TickerSniffer> = OnceLock::new();
+    static CASE_INSENSITIVE_TICKER_SNIFFER: OnceLock<TickerSniffer> = OnceLock::new();
+
+    let ticker_sniffer_cell = if is_case_sensitive {
+        &CASE_SENSITIVE_TICKER_SNIFFER
+    } else {
+        &CASE_INSENSITIVE_TICKER_SNIFFER
+    };
+
+    if let Some(ticker_sniffer) = ticker_sniffer_cell.get() {
+        return Ok(ticker_sniffer);
+    }
 
-    let results_ticker_symbol_frequency_map = company_token_processor?.process_text_doc(text)?;
+    let ticker_sniffer =
+        TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, is_case_sensitive)?;
 
-    Ok(results_ticker_symbol_frequency_map)
+    Ok(ticker_sniffer_cell.get_or_init(|| ticker_sniffer))
 }
@@ -1,6 +1,9 @@
 use log::error;
 use std::io::{self, Read};
-use ticker_sniffer::{extract_tickers_from_text, sort_results};
+use ticker_sniffer::{
+    extract_tickers_from_text, sort_results, CompanyNameConsistencyIssue, MarkupFormat, MarkupText,
+    TickerSniffer, TokenSequenceCollision, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+};
 
 fn main() {
     // Initialize the logger
@@ -9,6 +12,31 @@ fn main() {
         env_logger::init();
     }
 
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("lint") => std::process::exit(lint(&args[1..])),
+        Some("collisions") => std::process::exit(collisions()),
+        Some("verify") => std::process::exit(verify()),
+        _ => {}
+    }
+
+    // Determine the markup language of the input (e.g., `--format html`)
+    let format = match args.first().map(String::as_str) {
+        Some("--format") => match args.get(1).map(|format| format.parse::<MarkupFormat>()) {
+            Some(Ok(format)) => format,
+            Some(Err(e)) => {
+                eprintln!("{}", e);
+                std::process::exit(2);
+            }
+            None => {
+                eprintln!("Missing format after '--format'");
+                std::process::exit(2);
+            }
+        },
+        _ => MarkupFormat::PlainText,
+    };
+
     // Read the input text from stdin
     let mut input = String::new();
     if let Err(e) = io::stdin().read_to_string(&mut input) {
@@ -17,7 +45,7 @@ fn main() {
     }
 
     // Extract tickers from the input text
-    match extract_tickers_from_text(&input, true) {
+    match extract_tickers_from_text(MarkupText::new(&input, format).text(), true) {
         Ok(results) => {
             let sorted_results = sort_results(results);
 
@@ -31,3 +59,167 @@ fn main() {
         }
     }
 }
+
+/// Prints the token sequences of the embedded company symbol list which can be
+/// matched to more than one ticker symbol, most ambiguous first, as rows of
+/// tab-separated values.
+///
+/// Usage: `ticker-sniffer-cli collisions`
+///
+/// Returns the exit code: `0` on success, or `2` if the symbol index cannot be loaded.
+fn collisions() -> i32 {
+    let collisions = TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true)
+        .and_then(|ticker_sniffer| ticker_sniffer.collect_token_sequence_collisions());
+
+    match collisions {
+        Ok(collisions) => {
+            println!("{}", TokenSequenceCollision::TSV_HEADER);
+            for collision in &collisions {
+                println!("{}", collision.to_tsv_row());
+            }
+
+            0
+        }
+        Err(e) => {
+            eprintln!("Failed to collect collisions: {}", e);
+            2
+        }
+    }
+}
+
+/// Extracts each company name and alternate name of the embedded company symbol
+/// list, printing the ones which do not extract to exactly their own ticker
+/// symbol as rows of tab-separated values.
+///
+/// Usage: `ticker-sniffer-cli verify`
+///
+/// Returns the exit code: `0` if every name is consistent, `1` if issues were
+/// found, or `2` if the check fails.
+fn verify() -> i32 {
+    let issues = TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true)
+        .and_then(|ticker_sniffer| ticker_sniffer.check_company_name_consistency());
+
+    match issues {
+        Ok(issues) => {
+            println!("{}", CompanyNameConsistencyIssue::TSV_HEADER);
+            for issue in &issues {
+                println!("{}", issue.to_tsv_row());
+            }
+
+            if issues.is_empty() {
+                0
+            } else {
+                1
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to check company names: {}", e);
+            2
+        }
+    }
+}
+
+/// Validates a company symbol list CSV file, printing each issue as a row of
+/// tab-separated values.
+///
+/// Usage: `ticker-sniffer-cli lint <path> [--allow <kind>]... [--stop-words <preset>]`
+///
+/// Returns the exit code: `0` if no issues were found, `1` if issues were found,
+/// or `2` if the arguments are invalid or the file cannot be read.
+#[cfg(feature = "csv")]
+fn lint(args: &[String]) -> i32 {
+    use ticker_sniffer::{
+        CompanySymbolListIssue, CompanySymbolListIssueKind, CompanySymbolListPreprocessor,
+        CompanySymbolListValidator, CompanyTokenProcessorConfig, StopWordPreset, StopWords,
+    };
+
+    let mut path = None;
+    let mut allowed_kinds = Vec::new();
+    let mut stop_word_preset = StopWordPreset::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--allow" => match args
+                .next()
+                .map(|kind| kind.parse::<CompanySymbolListIssueKind>())
+            {
+                Some(Ok(kind)) => allowed_kinds.push(kind),
+                Some(Err(e)) => {
+                    eprintln!("{}", e);
+                    return 2;
+                }
+                None => {
+                    eprintln!("Missing issue kind after '--allow'");
+                    return 2;
+                }
+            },
+            "--stop-words" => match args.next().map(|preset| preset.parse::<StopWordPreset>()) {
+                Some(Ok(preset)) => stop_word_preset = preset,
+                Some(Err(e)) => {
+                    eprintln!("{}", e);
+                    return 2;
+                }
+                None => {
+                    eprintln!("Missing stop word preset after '--stop-words'");
+                    return 2;
+                }
+            },
+            _ if path.is_none() => path = Some(arg),
+            _ => {
+                eprintln!("Unexpected argument '{}'", arg);
+                return 2;
+            }
+        }
+    }
+
+    let Some(path) = path else {
+        eprintln!(
+            "Usage: ticker-sniffer-cli lint <path> [--allow <kind>]... [--stop-words <preset>]"
+        );
+        return 2;
+    };
+
+    let config = match CompanyTokenProcessorConfig::builder()
+        .stop_words(StopWords::new(stop_word_preset))
+        .build()
+    {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{}", e);
+            return 2;
+        }
+    };
+
+    let company_symbol_list =
+        match CompanySymbolListPreprocessor::read_company_symbol_list_from_path(path) {
+            Ok(company_symbol_list) => company_symbol_list,
+            Err(e) => {
+                eprintln!("Failed to read '{}': {}", path, e);
+                return 2;
+            }
+        };
+
+    let issues: Vec<CompanySymbolListIssue> =
+        CompanySymbolListValidator::validate(&company_symbol_list, &config)
+            .into_iter()
+            .filter(|issue| !allowed_kinds.contains(&issue.kind))
+            .collect();
+
+    println!("{}", CompanySymbolListIssue::TSV_HEADER);
+    for issue in &issues {
+        println!("{}", issue.to_tsv_row());
+    }
+
+    if issues.is_empty() {
+        0
+    } else {
+        1
+    }
+}
+
+#[cfg(not(feature = "csv"))]
+fn lint(_args: &[String]) -> i32 {
+    eprintln!("The `lint` subcommand requires the `csv` feature");
+    2
+}
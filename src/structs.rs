@@ -1,15 +1,69 @@
+pub mod asset_type;
+pub use asset_type::AssetType;
+
+pub mod company_metadata;
+pub use company_metadata::CompanyMetadata;
+
+pub mod company_name_consistency_issue;
+pub use company_name_consistency_issue::{
+    CompanyNameConsistencyIssue, CompanyNameConsistencyIssueKind,
+};
+
+pub mod company_symbol_list_validator;
+pub use company_symbol_list_validator::{
+    CompanySymbolListIssue, CompanySymbolListIssueKind, CompanySymbolListValidator,
+};
+
+pub mod company_symbol_list_overlay;
+pub use company_symbol_list_overlay::{CompanySymbolListOverlay, CompanySymbolListPatch};
+
 pub mod company_token_processor;
-pub use company_token_processor::{CompanyTokenProcessor, CompanyTokenProcessorConfig};
+pub use company_token_processor::CompanyTokenProcessor;
+
+pub mod company_token_processor_config;
+pub use company_token_processor_config::{
+    CompanyTokenProcessorConfig, CompanyTokenProcessorConfigBuilder,
+};
 
 pub mod token_parity_state;
 pub use token_parity_state::TokenParityState;
 
+pub mod market;
+pub use market::Market;
+
+pub mod markup_text;
+pub use markup_text::{MarkupFormat, MarkupText};
+
+pub mod ticker_sniffer;
+pub use ticker_sniffer::TickerSniffer;
+
+pub mod ticker_symbol_mention;
+pub use ticker_symbol_mention::{TickerSymbolMention, TickerSymbolMentionKind};
+
+pub mod ticker_symbol_result;
+pub use ticker_symbol_result::TickerSymbolResult;
+
 pub mod token_range_state;
 pub use token_range_state::TokenRangeState;
 
+pub mod extraction_trace;
+pub use extraction_trace::{ExtractionTrace, TickerSymbolTrace, TickerSymbolTraceOutcome};
+
+pub mod date;
+pub use date::Date;
+
 pub mod error;
 pub use error::Error;
 
+pub mod token_sequence_collision;
+pub use token_sequence_collision::{TokenSequenceCollision, TokenSequenceCollisionMatch};
+
+pub mod stop_words;
+pub use stop_words::{StopWordPreset, StopWords};
+
+pub mod token_span;
+pub use token_span::TokenSpan;
+
 pub mod tokenizer;
 pub use tokenizer::Tokenizer;
 
@@ -19,8 +73,13 @@ pub use token_mapper::TokenMapper;
 pub mod company_token_mapper;
 pub use company_token_mapper::CompanyTokenMapper;
 
+#[cfg(feature = "csv")]
 pub mod company_symbol_list_preprocessor;
+#[cfg(feature = "csv")]
 pub use company_symbol_list_preprocessor::CompanySymbolListPreprocessor;
 
+pub mod symbol_index;
+pub use symbol_index::SymbolIndex;
+
 pub mod resource_container;
 pub use resource_container::ResourceContainer;
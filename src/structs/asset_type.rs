@@ -0,0 +1,75 @@
+use crate::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// The class of security a ticker symbol represents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AssetType {
+    /// Common stock.
+    Stock,
+
+    /// An exchange-traded fund or note.
+    Etf,
+
+    /// A mutual fund.
+    MutualFund,
+
+    /// A warrant.
+    Warrant,
+
+    /// A unit, typically of a SPAC, combining shares with warrants or rights.
+    Unit,
+
+    /// Preferred stock.
+    Preferred,
+
+    /// A right.
+    Right,
+}
+
+impl AssetType {
+    /// All asset types, in declaration order.
+    pub const ALL: [AssetType; 7] = [
+        AssetType::Stock,
+        AssetType::Etf,
+        AssetType::MutualFund,
+        AssetType::Warrant,
+        AssetType::Unit,
+        AssetType::Preferred,
+        AssetType::Right,
+    ];
+
+    /// Returns the label used for the asset type in the company symbol list.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AssetType::Stock => "Stock",
+            AssetType::Etf => "ETF",
+            AssetType::MutualFund => "Mutual Fund",
+            AssetType::Warrant => "Warrant",
+            AssetType::Unit => "Unit",
+            AssetType::Preferred => "Preferred",
+            AssetType::Right => "Right",
+        }
+    }
+}
+
+impl fmt::Display for AssetType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for AssetType {
+    type Err = Error;
+
+    /// Parses an asset type from its label, ignoring case (e.g., "ETF" or "mutual fund").
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        AssetType::ALL
+            .into_iter()
+            .find(|asset_type| asset_type.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| Error::ParserError(format!("Unknown asset type '{}'", s)))
+    }
+}
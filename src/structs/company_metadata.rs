@@ -0,0 +1,212 @@
+use crate::types::{AlternateCompanyName, CompanyName, TickerSymbol};
+use crate::{AssetType, Date, Market};
+
+/// The descriptive data associated with a ticker symbol in the company symbol list.
+///
+/// An entry may be limited to the period in which its ticker symbol and names were
+/// valid (e.g., "FB" for "Facebook", until it was renamed to "META"). A ticker
+/// symbol may appear in multiple entries, as long as their validity periods do
+/// not overlap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompanyMetadata {
+    /// The ticker symbol of the company.
+    pub ticker_symbol: TickerSymbol,
+
+    /// The primary name of the company, if available.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub company_name: Option<CompanyName>,
+
+    /// Alternate names or aliases for the company.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub alternate_company_names: Vec<AlternateCompanyName>,
+
+    /// The class of security the ticker symbol represents, if known.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub asset_type: Option<AssetType>,
+
+    /// The market the ticker symbol is listed on.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub market: Market,
+
+    /// The first date the entry was valid, if it was not always valid.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub valid_from: Option<Date>,
+
+    /// The first date the entry was no longer valid, if it is no longer current.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub valid_until: Option<Date>,
+
+    /// The current ticker symbol of the company, if the entry is no longer
+    /// current and the company is still listed under a different symbol.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub successor_ticker_symbol: Option<TickerSymbol>,
+}
+
+impl CompanyMetadata {
+    /// Creates company metadata with no asset type, which is always valid.
+    ///
+    /// The market is determined from the ticker symbol's suffix (e.g., "VOD.L"
+    /// is listed in London), defaulting to `Market::Us`.
+    ///
+    /// # Arguments
+    /// * `ticker_symbol` - The ticker symbol of the company.
+    /// * `company_name` - The primary name of the company, if available.
+    /// * `alternate_company_names` - Alternate names or aliases for the company.
+    pub fn new(
+        ticker_symbol: &str,
+        company_name: Option<&str>,
+        alternate_company_names: &[&str],
+    ) -> Self {
+        Self {
+            ticker_symbol: ticker_symbol.to_string(),
+            company_name: company_name.map(|name| name.to_string()),
+            alternate_company_names: alternate_company_names
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+            asset_type: None,
+            market: Market::from_ticker_symbol(ticker_symbol),
+            valid_from: None,
+            valid_until: None,
+            successor_ticker_symbol: None,
+        }
+    }
+
+    /// Sets the asset type.
+    ///
+    /// # Arguments
+    /// * `asset_type` - The class of security the ticker symbol represents.
+    pub fn with_asset_type(mut self, asset_type: AssetType) -> Self {
+        self.asset_type = Some(asset_type);
+        self
+    }
+
+    /// Sets the market, qualifying the ticker symbol with the market's suffix if
+    /// it is not already.
+    ///
+    /// # Arguments
+    /// * `market` - The market the ticker symbol is listed on.
+    pub fn with_market(mut self, market: Market) -> Self {
+        self.ticker_symbol = market.qualify_ticker_symbol(&self.ticker_symbol);
+        self.market = market;
+        self
+    }
+
+    /// Sets the period in which the entry is valid.
+    ///
+    /// # Arguments
+    /// * `valid_from` - The first date the entry was valid, if it was not always valid.
+    /// * `valid_until` - The first date the entry was no longer valid, if it is no
+    ///   longer current.
+    pub fn with_validity(mut self, valid_from: Option<Date>, valid_until: Option<Date>) -> Self {
+        self.valid_from = valid_from;
+        self.valid_until = valid_until;
+        self
+    }
+
+    /// Sets the current ticker symbol of the company.
+    ///
+    /// # Arguments
+    /// * `successor_ticker_symbol` - The ticker symbol which replaced this one.
+    pub fn with_successor_ticker_symbol(mut self, successor_ticker_symbol: &str) -> Self {
+        self.successor_ticker_symbol = Some(successor_ticker_symbol.to_string());
+        self
+    }
+
+    /// Determines whether the entry is valid as of the given date.
+    ///
+    /// # Arguments
+    /// * `as_of_date` - The date to check, or `None` for the present, in which case
+    ///   only entries which are still current are valid.
+    pub fn is_valid_as_of(&self, as_of_date: Option<Date>) -> bool {
+        match as_of_date {
+            Some(as_of_date) => {
+                self.valid_from
+                    .is_none_or(|valid_from| valid_from <= as_of_date)
+                    && self
+                        .valid_until
+                        .is_none_or(|valid_until| as_of_date < valid_until)
+            }
+            None => self.valid_until.is_none(),
+        }
+    }
+
+    /// Determines whether the validity periods of two entries overlap.
+    ///
+    /// # Arguments
+    /// * `other` - The entry to compare with.
+    pub(crate) fn has_overlapping_validity(&self, other: &CompanyMetadata) -> bool {
+        let starts_before_end = |start: Option<_>, end: Option<_>| match (start, end) {
+            (Some(start), Some(end)) => start < end,
+            _ => true,
+        };
+
+        starts_before_end(self.valid_from, other.valid_until)
+            && starts_before_end(other.valid_from, self.valid_until)
+    }
+
+    /// Ranks how closely the company's names match a search query.
+    ///
+    /// The primary company name and each alternate name are compared against the
+    /// query, case insensitively. An exact match ranks highest, followed by a
+    /// match at the start of the name, a match at the start of any word in the
+    /// name, and finally a match anywhere in the name. Matches on the primary
+    /// company name are favored over equivalent matches on alternate names.
+    ///
+    /// # Arguments
+    /// * `lowercased_query` - The search query, already converted to lowercase.
+    ///
+    /// # Returns
+    /// * The rank of the best matching name, where lower is better, or `None` if
+    ///   no name contains the query.
+    pub(crate) fn rank_name_match(&self, lowercased_query: &str) -> Option<usize> {
+        if lowercased_query.is_empty() {
+            return None;
+        }
+
+        self.company_name
+            .iter()
+            .map(|name| (name, 0))
+            .chain(self.alternate_company_names.iter().map(|name| (name, 1)))
+            .filter_map(|(name, name_rank)| {
+                let lowercased_name = name.to_lowercase();
+
+                let match_rank = if lowercased_name == lowercased_query {
+                    0
+                } else if lowercased_name.starts_with(lowercased_query) {
+                    1
+                } else if lowercased_name
+                    .split(|c: char| !c.is_alphanumeric())
+                    .any(|word| word.starts_with(lowercased_query))
+                {
+                    2
+                } else if lowercased_name.contains(lowercased_query) {
+                    3
+                } else {
+                    return None;
+                };
+
+                Some(match_rank * 2 + name_rank)
+            })
+            .min()
+    }
+}
+
+impl From<(TickerSymbol, Option<CompanyName>, Vec<AlternateCompanyName>)> for CompanyMetadata {
+    /// Converts an entry of the tuple form previously used by `CompanySymbolList`
+    /// (i.e., ticker symbol, company name, and alternate names).
+    fn from(
+        (ticker_symbol, company_name, alternate_company_names): (
+            TickerSymbol,
+            Option<CompanyName>,
+            Vec<AlternateCompanyName>,
+        ),
+    ) -> Self {
+        Self {
+            company_name,
+            alternate_company_names,
+            ..Self::new(&ticker_symbol, None, &[])
+        }
+    }
+}
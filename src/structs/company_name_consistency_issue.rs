@@ -0,0 +1,68 @@
+use crate::types::TickerSymbol;
+use std::fmt;
+
+/// Describes how a company name failed to extract to its own ticker symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CompanyNameConsistencyIssueKind {
+    /// The company name does not extract to its own ticker symbol (e.g., it is
+    /// filtered out as stop words, or is below the coverage threshold).
+    Unresolved,
+
+    /// The company name extracts to its own ticker symbol, but also to others.
+    Ambiguous,
+}
+
+impl CompanyNameConsistencyIssueKind {
+    /// Returns a stable identifier for the issue kind, suitable for
+    /// machine-readable reports (e.g., "unresolved").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CompanyNameConsistencyIssueKind::Unresolved => "unresolved",
+            CompanyNameConsistencyIssueKind::Ambiguous => "ambiguous",
+        }
+    }
+}
+
+impl fmt::Display for CompanyNameConsistencyIssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A company name, or alternate name, of the symbol list which does not
+/// extract to exactly its own ticker symbol.
+///
+/// Issues are collected with `TickerSniffer::check_company_name_consistency`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompanyNameConsistencyIssue {
+    /// The kind of issue.
+    pub kind: CompanyNameConsistencyIssueKind,
+
+    /// The ticker symbol the company name belongs to.
+    pub ticker_symbol: TickerSymbol,
+
+    /// The company name, or alternate name, which was extracted from.
+    pub company_name: String,
+
+    /// The other ticker symbols extracted from the company name, sorted.
+    pub other_ticker_symbols: Vec<TickerSymbol>,
+}
+
+impl CompanyNameConsistencyIssue {
+    /// The header of the tab-separated values written by `to_tsv_row`.
+    pub const TSV_HEADER: &'static str = "kind\tticker_symbol\tcompany_name\tother_ticker_symbols";
+
+    /// Formats the issue as a row of tab-separated values, with the columns of
+    /// `TSV_HEADER`. Other ticker symbols are separated by commas.
+    pub fn to_tsv_row(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}",
+            self.kind,
+            self.ticker_symbol,
+            self.company_name.replace(['\t', '\n', '\r'], " "),
+            self.other_ticker_symbols.join(",")
+        )
+    }
+}
@@ -0,0 +1,411 @@
+use crate::types::{CompanySymbolList, TickerSymbol};
+use crate::CompanyMetadata;
+#[cfg(feature = "csv")]
+use crate::CompanySymbolListPreprocessor;
+#[cfg(any(feature = "csv", feature = "toml"))]
+use crate::Error;
+#[cfg(feature = "toml")]
+use crate::Market;
+#[cfg(feature = "csv")]
+use csv::ReaderBuilder;
+#[cfg(any(feature = "csv", feature = "toml"))]
+use std::fs;
+#[cfg(feature = "csv")]
+use std::io::Read;
+#[cfg(any(feature = "csv", feature = "toml"))]
+use std::path::Path;
+
+/// A single change to apply to a company symbol list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "action", rename_all = "lowercase")
+)]
+pub enum CompanySymbolListPatch {
+    /// Adds a company. If the ticker symbol already exists, the alternate names
+    /// of each entry it matches are extended, and their company name, asset type,
+    /// and successor ticker symbol are only set if they were previously missing.
+    Add(CompanyMetadata),
+
+    /// Removes a ticker symbol.
+    Remove { ticker_symbol: TickerSymbol },
+
+    /// Replaces the entries of a ticker symbol which it matches, including their
+    /// names and asset type, adding it if none exist.
+    Replace(CompanyMetadata),
+}
+
+/// An ordered set of patches to layer on top of a company symbol list.
+///
+/// This allows the embedded company symbol list to be customized (e.g., adding
+/// aliases, removing ticker symbols which cause false positives, or correcting
+/// company names) without maintaining a copy of the full list.
+///
+/// Patches can be loaded from a CSV file with `Action`, `Symbol`, `Company Name`,
+/// `Alternate Names`, `Asset Type`, `Market`, `Valid From`, `Valid Until`, and
+/// `Successor Symbol` columns, where `Action` is one of `add`,
+/// `remove`, or `replace`. With the `toml` feature, patches can also be loaded from TOML.
+///
+/// # Example
+/// ```
+/// use ticker_sniffer::{CompanyMetadata, CompanySymbolListOverlay};
+///
+/// let csv = "Action,Symbol,Company Name,Alternate Names\nadd,IBM,,Big Blue\nremove,ACME,,\n";
+/// let overlay = CompanySymbolListOverlay::from_csv_str(csv).unwrap();
+///
+/// let mut company_symbol_list = vec![
+///     CompanyMetadata::new("IBM", Some("International Business Machines"), &[]),
+///     CompanyMetadata::new("ACME", Some("Acme Corporation"), &[]),
+/// ];
+/// overlay.apply(&mut company_symbol_list);
+///
+/// assert_eq!(company_symbol_list.len(), 1);
+/// assert_eq!(company_symbol_list[0].alternate_company_names, vec!["Big Blue".to_string()]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompanySymbolListOverlay {
+    /// The patches, in the order they are applied.
+    pub patches: Vec<CompanySymbolListPatch>,
+}
+
+impl CompanySymbolListOverlay {
+    /// Creates an overlay from the given patches.
+    ///
+    /// # Arguments
+    /// * `patches` - The patches, in the order they should be applied.
+    pub fn new(patches: Vec<CompanySymbolListPatch>) -> Self {
+        Self { patches }
+    }
+
+    /// Parses an overlay from a CSV-formatted string.
+    ///
+    /// # Arguments
+    /// * `csv_str` - A reference to a string containing the CSV data.
+    ///
+    /// # Errors
+    /// Returns `Error::MissingHeaderError` if the `Action` or `Symbol` column is
+    /// missing, or `Error::CsvRowError` if a row is malformed or has an unknown action.
+    #[cfg(feature = "csv")]
+    pub fn from_csv_str(csv_str: &str) -> Result<Self, Error> {
+        Self::from_csv_reader(csv_str.as_bytes())
+    }
+
+    /// Parses an overlay from a CSV file.
+    ///
+    /// # Arguments
+    /// * `path` - The path to the CSV file.
+    ///
+    /// # Errors
+    /// Returns `Error::IoError` if the file cannot be read, or an error if the CSV
+    /// data cannot be parsed.
+    #[cfg(feature = "csv")]
+    pub fn from_csv_path<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        Self::from_csv_reader(fs::File::open(path)?)
+    }
+
+    /// Parses an overlay from a reader which yields CSV data.
+    ///
+    /// # Arguments
+    /// * `reader` - A reader which yields CSV data.
+    ///
+    /// # Errors
+    /// Returns `Error::MissingHeaderError` if the `Action` or `Symbol` column is
+    /// missing, or `Error::CsvRowError` if a row is malformed or has an unknown action.
+    #[cfg(feature = "csv")]
+    pub fn from_csv_reader<R: Read>(reader: R) -> Result<Self, Error> {
+        let mut reader = ReaderBuilder::new().has_headers(true).from_reader(reader);
+
+        let headers = reader.headers()?.clone();
+        let get_header_idx = |header: &str| headers.iter().position(|h| h == header);
+
+        let action_idx = get_header_idx("Action")
+            .ok_or_else(|| Error::MissingHeaderError("Action".to_string()))?;
+        let symbol_idx = get_header_idx("Symbol")
+            .ok_or_else(|| Error::MissingHeaderError("Symbol".to_string()))?;
+        let company_name_idx = get_header_idx("Company Name");
+        let alternate_names_idx = get_header_idx("Alternate Names");
+        let asset_type_idx = get_header_idx("Asset Type");
+        let market_idx = get_header_idx("Market");
+        let valid_from_idx = get_header_idx("Valid From");
+        let valid_until_idx = get_header_idx("Valid Until");
+        let successor_symbol_idx = get_header_idx("Successor Symbol");
+
+        let mut patches = Vec::new();
+
+        for record in reader.records() {
+            let record = record?;
+            let line = record.position().map(|position| position.line());
+
+            let ticker_symbol = record
+                .get(symbol_idx)
+                .map(|symbol| symbol.trim().to_uppercase())
+                .filter(|symbol| !symbol.is_empty())
+                .ok_or_else(|| Error::CsvRowError {
+                    line,
+                    column: Some("Symbol".to_string()),
+                    message: "Missing 'Symbol' field".to_string(),
+                    source: None,
+                })?;
+
+            let company_name = company_name_idx
+                .and_then(|idx| record.get(idx))
+                .map(|name| name.trim())
+                .filter(|name| !name.is_empty())
+                .map(|name| name.to_string());
+
+            let alternate_company_names: Vec<String> = alternate_names_idx
+                .and_then(|idx| record.get(idx))
+                .map(|names| {
+                    names
+                        .split(',')
+                        .map(|name| name.trim())
+                        .filter(|name| !name.is_empty())
+                        .map(|name| name.to_string())
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            let asset_type = CompanySymbolListPreprocessor::parse_optional_field(
+                &record,
+                asset_type_idx,
+                "Asset Type",
+            )?;
+
+            let market =
+                CompanySymbolListPreprocessor::parse_market(&record, market_idx, &ticker_symbol)?;
+            let ticker_symbol = market.qualify_ticker_symbol(&ticker_symbol);
+
+            let company_metadata = CompanyMetadata {
+                ticker_symbol: ticker_symbol.clone(),
+                company_name,
+                alternate_company_names,
+                asset_type,
+                market,
+                valid_from: CompanySymbolListPreprocessor::parse_optional_field(
+                    &record,
+                    valid_from_idx,
+                    "Valid From",
+                )?,
+                valid_until: CompanySymbolListPreprocessor::parse_optional_field(
+                    &record,
+                    valid_until_idx,
+                    "Valid Until",
+                )?,
+                successor_ticker_symbol: successor_symbol_idx
+                    .and_then(|idx| record.get(idx))
+                    .map(|symbol| symbol.trim().to_uppercase())
+                    .filter(|symbol| !symbol.is_empty()),
+            };
+
+            let action = record.get(action_idx).unwrap_or_default().trim();
+
+            let patch = match action.to_lowercase().as_str() {
+                "add" => CompanySymbolListPatch::Add(company_metadata),
+                "remove" => CompanySymbolListPatch::Remove { ticker_symbol },
+                "replace" => CompanySymbolListPatch::Replace(company_metadata),
+                _ => {
+                    return Err(Error::CsvRowError {
+                        line,
+                        column: Some("Action".to_string()),
+                        message: format!(
+                            "Unknown action '{}', expected 'add', 'remove', or 'replace'",
+                            action
+                        ),
+                        source: None,
+                    })
+                }
+            };
+
+            patches.push(patch);
+        }
+
+        Ok(Self { patches })
+    }
+
+    /// Parses an overlay from a TOML-formatted string.
+    ///
+    /// The TOML is expected to contain a `patches` array of tables, each with an
+    /// `action` of `add`, `remove`, or `replace`, and a `ticker_symbol`. Add and
+    /// replace patches may also specify `company_name`, `alternate_company_names`,
+    /// `asset_type` (e.g., `"Etf"`), and `market` (e.g., `"London"`).
+    ///
+    /// # Arguments
+    /// * `toml_str` - A reference to a string containing the TOML data.
+    ///
+    /// # Errors
+    /// Returns `Error::TomlError` if the TOML data cannot be parsed.
+    ///
+    /// # Example
+    /// ```
+    /// use ticker_sniffer::CompanySymbolListOverlay;
+    ///
+    /// let overlay = CompanySymbolListOverlay::from_toml_str(r#"
+    ///     [[patches]]
+    ///     action = "add"
+    ///     ticker_symbol = "IBM"
+    ///     alternate_company_names = ["Big Blue"]
+    ///
+    ///     [[patches]]
+    ///     action = "remove"
+    ///     ticker_symbol = "ACME"
+    /// "#).unwrap();
+    ///
+    /// assert_eq!(overlay.patches.len(), 2);
+    /// ```
+    #[cfg(feature = "toml")]
+    pub fn from_toml_str(toml_str: &str) -> Result<Self, Error> {
+        let mut overlay: Self = toml::from_str(toml_str).map_err(Error::TomlError)?;
+
+        // Normalize ticker symbols to match the company symbol list, including
+        // qualifying them with their market's suffix
+        for patch in &mut overlay.patches {
+            match patch {
+                CompanySymbolListPatch::Add(company_metadata)
+                | CompanySymbolListPatch::Replace(company_metadata) => {
+                    let ticker_symbol = company_metadata.ticker_symbol.to_uppercase();
+
+                    // An omitted market is determined from the ticker symbol's suffix
+                    if company_metadata.market == Market::Us {
+                        company_metadata.market = Market::from_ticker_symbol(&ticker_symbol);
+                    }
+
+                    company_metadata.ticker_symbol = company_metadata
+                        .market
+                        .qualify_ticker_symbol(&ticker_symbol);
+
+                    if let Some(successor_ticker_symbol) =
+                        &mut company_metadata.successor_ticker_symbol
+                    {
+                        *successor_ticker_symbol = successor_ticker_symbol.to_uppercase();
+                    }
+                }
+                CompanySymbolListPatch::Remove { ticker_symbol } => {
+                    *ticker_symbol = ticker_symbol.to_uppercase();
+                }
+            }
+        }
+
+        Ok(overlay)
+    }
+
+    /// Parses an overlay from a TOML file.
+    ///
+    /// # Arguments
+    /// * `path` - The path to the TOML file.
+    ///
+    /// # Errors
+    /// Returns `Error::IoError` if the file cannot be read, or `Error::TomlError`
+    /// if the TOML data cannot be parsed.
+    #[cfg(feature = "toml")]
+    pub fn from_toml_path<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        Self::from_toml_str(&fs::read_to_string(path)?)
+    }
+
+    /// Applies each patch, in order, to the provided company symbol list.
+    ///
+    /// Add and replace patches match the entries with the same ticker symbol and
+    /// market whose validity periods overlap their own. A patch which is still
+    /// current (i.e., without a `valid_until` date) only matches entries which
+    /// are still current, so that historical entries are left untouched.
+    ///
+    /// # Arguments
+    /// * `company_symbol_list` - The company symbol list to modify.
+    pub fn apply(&self, company_symbol_list: &mut CompanySymbolList) {
+        for patch in &self.patches {
+            match patch {
+                CompanySymbolListPatch::Add(company_metadata) => {
+                    let mut is_matched = false;
+
+                    for existing_company_metadata in
+                        company_symbol_list
+                            .iter_mut()
+                            .filter(|existing_company_metadata| {
+                                Self::is_patched(existing_company_metadata, company_metadata)
+                            })
+                    {
+                        is_matched = true;
+
+                        if existing_company_metadata.company_name.is_none() {
+                            existing_company_metadata
+                                .company_name
+                                .clone_from(&company_metadata.company_name);
+                        }
+
+                        if existing_company_metadata.asset_type.is_none() {
+                            existing_company_metadata.asset_type = company_metadata.asset_type;
+                        }
+
+                        if existing_company_metadata.successor_ticker_symbol.is_none() {
+                            existing_company_metadata
+                                .successor_ticker_symbol
+                                .clone_from(&company_metadata.successor_ticker_symbol);
+                        }
+
+                        for alt_company_name in &company_metadata.alternate_company_names {
+                            if !existing_company_metadata
+                                .alternate_company_names
+                                .contains(alt_company_name)
+                            {
+                                existing_company_metadata
+                                    .alternate_company_names
+                                    .push(alt_company_name.clone());
+                            }
+                        }
+                    }
+
+                    if !is_matched {
+                        company_symbol_list.push(company_metadata.clone());
+                    }
+                }
+                CompanySymbolListPatch::Remove { ticker_symbol } => {
+                    company_symbol_list.retain(|existing_company_metadata| {
+                        &existing_company_metadata.ticker_symbol != ticker_symbol
+                    });
+                }
+                CompanySymbolListPatch::Replace(company_metadata) => {
+                    // The first matching entry is replaced in place, and any other
+                    // matching entries (e.g., stale duplicates) are removed
+                    match company_symbol_list
+                        .iter()
+                        .position(|existing_company_metadata| {
+                            Self::is_patched(existing_company_metadata, company_metadata)
+                        }) {
+                        Some(idx) => {
+                            let mut entry_idx = 0;
+                            company_symbol_list.retain(|existing_company_metadata| {
+                                let is_retained = entry_idx <= idx
+                                    || !Self::is_patched(
+                                        existing_company_metadata,
+                                        company_metadata,
+                                    );
+                                entry_idx += 1;
+                                is_retained
+                            });
+
+                            company_symbol_list[idx] = company_metadata.clone();
+                        }
+                        None => company_symbol_list.push(company_metadata.clone()),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Determines whether an existing entry is matched by an add or replace patch.
+    ///
+    /// # Arguments
+    /// * `existing_company_metadata` - The entry of the company symbol list.
+    /// * `company_metadata` - The entry of the patch.
+    fn is_patched(
+        existing_company_metadata: &CompanyMetadata,
+        company_metadata: &CompanyMetadata,
+    ) -> bool {
+        existing_company_metadata.ticker_symbol == company_metadata.ticker_symbol
+            && existing_company_metadata.market == company_metadata.market
+            && existing_company_metadata.has_overlapping_validity(company_metadata)
+            && (company_metadata.valid_until.is_some()
+                || existing_company_metadata.valid_until.is_none())
+    }
+}
@@ -1,9 +1,14 @@
 use crate::types::CompanySymbolList;
-use crate::Error;
-use csv::ReaderBuilder;
+use crate::{CompanyMetadata, Error, Market};
+use csv::{ReaderBuilder, StringRecord};
 use flate2::read::GzDecoder;
-use std::io::Cursor;
+use std::fs;
 use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+
+/// The leading bytes which identify a Gzip stream.
+const GZIP_MAGIC_BYTES: [u8; 2] = [0x1f, 0x8b];
 
 pub struct CompanySymbolListPreprocessor {}
 
@@ -18,21 +23,64 @@ impl CompanySymbolListPreprocessor {
     ///
     /// # Errors
     ///
-    /// Returns an error if decompression or parsing fails (e.g., invalid Gzip format
-    /// or malformed CSV data).
+    /// Returns `Error::DecompressionError` if the data is not valid Gzip, or a CSV
+    /// error if the decompressed data cannot be parsed.
     pub fn extract_company_symbol_list_from_bytes(
         read_bytes: &[u8],
     ) -> Result<CompanySymbolList, Error> {
-        // Decompress the Gzip file
-        let mut decoder = GzDecoder::new(read_bytes);
+        Self::extract_company_symbol_list_from_reader(read_bytes)
+    }
+
+    /// Decompress and parse the company symbol list from a Gzip stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - A reader which yields Gzip-compressed CSV data.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::DecompressionError` if the data is not valid Gzip, or a CSV
+    /// error if the decompressed data cannot be parsed.
+    pub fn extract_company_symbol_list_from_reader<R: Read>(
+        reader: R,
+    ) -> Result<CompanySymbolList, Error> {
+        // Decompress the Gzip stream
+        let mut decoder = GzDecoder::new(reader);
         let mut decompressed_data = String::new();
-        decoder.read_to_string(&mut decompressed_data)?;
+        decoder
+            .read_to_string(&mut decompressed_data)
+            .map_err(Error::DecompressionError)?;
 
         // Use the utility function to parse the CSV data
         let company_symbol_list = Self::read_company_symbol_list_from_string(&decompressed_data)?;
         Ok(company_symbol_list)
     }
 
+    /// Read and parse a company symbol list from a file.
+    ///
+    /// Gzip-compressed files are detected by their header and decompressed
+    /// automatically, regardless of the file extension.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to a CSV file, optionally Gzip-compressed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::IoError` if the file cannot be read, or an error if
+    /// decompression or parsing fails.
+    pub fn read_company_symbol_list_from_path<P: AsRef<Path>>(
+        path: P,
+    ) -> Result<CompanySymbolList, Error> {
+        let read_bytes = fs::read(path)?;
+
+        if read_bytes.starts_with(&GZIP_MAGIC_BYTES) {
+            Self::extract_company_symbol_list_from_bytes(&read_bytes)
+        } else {
+            Self::read_company_symbol_list_from_reader(read_bytes.as_slice())
+        }
+    }
+
     /// Parse a company symbol list from a CSV-formatted string.
     ///
     /// # Arguments
@@ -41,8 +89,8 @@ impl CompanySymbolListPreprocessor {
     ///
     /// # Errors
     ///
-    /// Returns an error if the CSV reader encounters issues (e.g., invalid
-    /// format, missing headers, or missing data).
+    /// Returns `Error::MissingHeaderError` if the `Symbol` column is missing, or
+    /// `Error::CsvRowError` if a row is malformed or has an empty `Symbol` field.
     ///
     /// # Notes
     ///
@@ -50,35 +98,76 @@ impl CompanySymbolListPreprocessor {
     /// - `Symbol`: The primary symbol for the company.
     /// - `Company Name`: The name of the company (optional).
     /// - `Alternate Names`: A comma-separated list of alternate names (optional).
+    /// - `Asset Type`: The class of security, such as `Stock` or `ETF` (optional).
+    /// - `Market`: The market the symbol is listed on, such as `London` or `Tokyo`
+    ///   (optional). If omitted, the market is determined from the symbol's suffix
+    ///   (e.g., `VOD.L`), defaulting to `US`. Symbols are qualified with the
+    ///   market's suffix if they are not already.
+    /// - `Valid From`: The first date the entry was valid, as `YYYY-MM-DD` (optional).
+    /// - `Valid Until`: The first date the entry was no longer valid, as `YYYY-MM-DD`
+    ///   (optional).
+    /// - `Successor Symbol`: The current symbol of the company, if the entry is no
+    ///   longer current (optional).
     pub fn read_company_symbol_list_from_string(csv_str: &str) -> Result<CompanySymbolList, Error> {
+        Self::read_company_symbol_list_from_reader(csv_str.as_bytes())
+    }
+
+    /// Parse a company symbol list from a reader which yields uncompressed CSV data.
+    ///
+    /// See `read_company_symbol_list_from_string` for the expected format.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - A reader which yields CSV data.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::MissingHeaderError` if the `Symbol` column is missing, or
+    /// `Error::CsvRowError` if a row is malformed or has an empty `Symbol` field.
+    pub fn read_company_symbol_list_from_reader<R: Read>(
+        reader: R,
+    ) -> Result<CompanySymbolList, Error> {
         let mut company_symbols_list = CompanySymbolList::new();
 
-        // Use a cursor to simulate a file reader from the string
         let mut reader = ReaderBuilder::new()
             .has_headers(true) // Ensure headers are expected
-            .from_reader(Cursor::new(csv_str));
+            .from_reader(reader);
 
         // Extract column headers
-        let headers = reader
-            .headers()
-            .map_err(|e| Error::ParserError(format!("Failed to read headers: {}", e)))?
-            .clone();
+        let headers = reader.headers()?.clone();
+
+        let symbol_idx = headers
+            .iter()
+            .position(|h| h == "Symbol")
+            .ok_or_else(|| Error::MissingHeaderError("Symbol".to_string()))?;
+        let company_name_idx = headers.iter().position(|h| h == "Company Name");
+        let alternate_names_idx = headers.iter().position(|h| h == "Alternate Names");
+        let asset_type_idx = headers.iter().position(|h| h == "Asset Type");
+        let market_idx = headers.iter().position(|h| h == "Market");
+        let valid_from_idx = headers.iter().position(|h| h == "Valid From");
+        let valid_until_idx = headers.iter().position(|h| h == "Valid Until");
+        let successor_symbol_idx = headers.iter().position(|h| h == "Successor Symbol");
 
         for record in reader.records() {
-            let record =
-                record.map_err(|e| Error::ParserError(format!("Failed to read record: {}", e)))?;
+            let record = record?;
 
             // Extract values based on header names
             let symbol = record
-                .get(headers.iter().position(|h| h == "Symbol").unwrap())
-                .ok_or_else(|| Error::ParserError("Missing 'Symbol' field".to_string()))?;
+                .get(symbol_idx)
+                .filter(|symbol| !symbol.is_empty())
+                .ok_or_else(|| Error::CsvRowError {
+                    line: record.position().map(|position| position.line()),
+                    column: Some("Symbol".to_string()),
+                    message: "Missing 'Symbol' field".to_string(),
+                    source: None,
+                })?;
 
-            let company_name = record
-                .get(headers.iter().position(|h| h == "Company Name").unwrap())
+            let company_name = company_name_idx
+                .and_then(|idx| record.get(idx))
                 .map(|name| name.to_string());
 
             let comma_separated_alternate_names =
-                record.get(headers.iter().position(|h| h == "Alternate Names").unwrap());
+                alternate_names_idx.and_then(|idx| record.get(idx));
 
             let alternate_names: Vec<String> = if let Some(names) = comma_separated_alternate_names
             {
@@ -90,9 +179,80 @@ impl CompanySymbolListPreprocessor {
                 Vec::new() // Default to an empty vector if alternate names are missing
             };
 
-            company_symbols_list.push((symbol.to_uppercase(), company_name, alternate_names));
+            let asset_type = Self::parse_optional_field(&record, asset_type_idx, "Asset Type")?;
+
+            let symbol = symbol.to_uppercase();
+            let market = Self::parse_market(&record, market_idx, &symbol)?;
+
+            company_symbols_list.push(CompanyMetadata {
+                ticker_symbol: market.qualify_ticker_symbol(&symbol),
+                company_name,
+                alternate_company_names: alternate_names,
+                asset_type,
+                market,
+                valid_from: Self::parse_optional_field(&record, valid_from_idx, "Valid From")?,
+                valid_until: Self::parse_optional_field(&record, valid_until_idx, "Valid Until")?,
+                successor_ticker_symbol: successor_symbol_idx
+                    .and_then(|idx| record.get(idx))
+                    .map(|symbol| symbol.trim().to_uppercase())
+                    .filter(|symbol| !symbol.is_empty()),
+            });
         }
 
         Ok(company_symbols_list)
     }
+
+    /// Parse an optional field of a CSV record.
+    ///
+    /// # Arguments
+    ///
+    /// * `record` - The CSV record.
+    /// * `idx` - The index of the column, if present.
+    /// * `column` - The name of the column, used when reporting errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::CsvRowError` if the field cannot be parsed.
+    pub(crate) fn parse_optional_field<T: FromStr<Err = Error>>(
+        record: &StringRecord,
+        idx: Option<usize>,
+        column: &str,
+    ) -> Result<Option<T>, Error> {
+        match idx
+            .and_then(|idx| record.get(idx))
+            .filter(|field| !field.trim().is_empty())
+        {
+            Some(field) => field
+                .parse()
+                .map(Some)
+                .map_err(|err: Error| Error::CsvRowError {
+                    line: record.position().map(|position| position.line()),
+                    column: Some(column.to_string()),
+                    message: err.to_string(),
+                    source: Some(Box::new(err)),
+                }),
+            None => Ok(None),
+        }
+    }
+
+    /// Parse the optional `Market` field of a CSV record.
+    ///
+    /// # Arguments
+    ///
+    /// * `record` - The CSV record.
+    /// * `market_idx` - The index of the `Market` column, if present.
+    /// * `ticker_symbol` - The ticker symbol of the record, used to determine the
+    ///   market if the field is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::CsvRowError` if the field is not a known market.
+    pub(crate) fn parse_market(
+        record: &StringRecord,
+        market_idx: Option<usize>,
+        ticker_symbol: &str,
+    ) -> Result<Market, Error> {
+        Ok(Self::parse_optional_field(record, market_idx, "Market")?
+            .unwrap_or_else(|| Market::from_ticker_symbol(ticker_symbol)))
+    }
 }
@@ -0,0 +1,315 @@
+use crate::types::{CompanySymbolList, TickerSymbol};
+use crate::{CompanyTokenProcessorConfig, Error, Tokenizer};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+
+/// The kinds of data quality issues found by `CompanySymbolListValidator`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CompanySymbolListIssueKind {
+    /// A ticker symbol appears in multiple entries whose validity periods overlap.
+    DuplicateTickerSymbol,
+
+    /// A company name or alternate name produces no tokens (e.g., it only
+    /// contains punctuation), so it can never be matched.
+    EmptyCompanyName,
+
+    /// A company name or alternate name only contains stop words, which are
+    /// filtered from text documents, so it can never be matched.
+    StopWordCompanyName,
+
+    /// An alternate name is identical to the primary company name of a different
+    /// ticker symbol, so it is ambiguous.
+    AlternateNameMatchesCompanyName,
+
+    /// A ticker symbol is also a stop word (e.g., "ALL"), so it is easily
+    /// confused with ordinary text.
+    StopWordTickerSymbol,
+}
+
+impl CompanySymbolListIssueKind {
+    /// All issue kinds, in declaration order.
+    pub const ALL: [CompanySymbolListIssueKind; 5] = [
+        CompanySymbolListIssueKind::DuplicateTickerSymbol,
+        CompanySymbolListIssueKind::EmptyCompanyName,
+        CompanySymbolListIssueKind::StopWordCompanyName,
+        CompanySymbolListIssueKind::AlternateNameMatchesCompanyName,
+        CompanySymbolListIssueKind::StopWordTickerSymbol,
+    ];
+
+    /// Returns a stable identifier for the issue kind, suitable for
+    /// machine-readable reports (e.g., "duplicate_ticker_symbol").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CompanySymbolListIssueKind::DuplicateTickerSymbol => "duplicate_ticker_symbol",
+            CompanySymbolListIssueKind::EmptyCompanyName => "empty_company_name",
+            CompanySymbolListIssueKind::StopWordCompanyName => "stop_word_company_name",
+            CompanySymbolListIssueKind::AlternateNameMatchesCompanyName => {
+                "alternate_name_matches_company_name"
+            }
+            CompanySymbolListIssueKind::StopWordTickerSymbol => "stop_word_ticker_symbol",
+        }
+    }
+}
+
+impl fmt::Display for CompanySymbolListIssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for CompanySymbolListIssueKind {
+    type Err = Error;
+
+    /// Parses an issue kind from its identifier (e.g., "duplicate_ticker_symbol").
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        CompanySymbolListIssueKind::ALL
+            .into_iter()
+            .find(|kind| kind.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| Error::ParserError(format!("Unknown issue kind '{}'", s)))
+    }
+}
+
+/// A data quality issue found in an entry of a company symbol list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompanySymbolListIssue {
+    /// The kind of issue.
+    pub kind: CompanySymbolListIssueKind,
+
+    /// The 0-based position of the entry in the company symbol list.
+    pub entry_idx: usize,
+
+    /// The ticker symbol of the entry.
+    pub ticker_symbol: TickerSymbol,
+
+    /// The offending value (e.g., the company name which produces no tokens).
+    pub value: String,
+
+    /// The 0-based position of the other entry involved in the issue, if any
+    /// (e.g., the duplicate entry, or the entry whose company name is matched).
+    pub related_entry_idx: Option<usize>,
+
+    /// The ticker symbol of the other entry involved in the issue, if any.
+    pub related_ticker_symbol: Option<TickerSymbol>,
+}
+
+impl CompanySymbolListIssue {
+    /// The header of the tab-separated values written by `to_tsv_row`.
+    pub const TSV_HEADER: &'static str =
+        "entry_idx\tkind\tticker_symbol\tvalue\trelated_entry_idx\trelated_ticker_symbol";
+
+    /// Formats the issue as a row of tab-separated values, with the columns of
+    /// `TSV_HEADER`. Tabs and line breaks within values are replaced by spaces.
+    pub fn to_tsv_row(&self) -> String {
+        let sanitize = |value: &str| value.replace(['\t', '\n', '\r'], " ");
+
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            self.entry_idx,
+            self.kind,
+            sanitize(&self.ticker_symbol),
+            sanitize(&self.value),
+            self.related_entry_idx
+                .map(|related_entry_idx| related_entry_idx.to_string())
+                .unwrap_or_default(),
+            sanitize(self.related_ticker_symbol.as_deref().unwrap_or_default())
+        )
+    }
+}
+
+impl fmt::Display for CompanySymbolListIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} (entry {}): {} '{}'",
+            self.ticker_symbol, self.entry_idx, self.kind, self.value
+        )?;
+
+        if let (Some(related_entry_idx), Some(related_ticker_symbol)) =
+            (self.related_entry_idx, &self.related_ticker_symbol)
+        {
+            write!(
+                f,
+                " (see {}, entry {})",
+                related_ticker_symbol, related_entry_idx
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Validates the data quality of a company symbol list, such as one read with
+/// `CompanySymbolListPreprocessor`.
+///
+/// # Example
+/// ```
+/// use ticker_sniffer::{
+///     CompanyMetadata, CompanySymbolListIssueKind, CompanySymbolListValidator,
+///     DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+/// };
+///
+/// let company_symbol_list = vec![
+///     CompanyMetadata::new("ACME", Some("Acme Corporation"), &["Acme", "The"]),
+///     CompanyMetadata::new("ACME", Some("Acme Corporation"), &[]),
+/// ];
+///
+/// let issues = CompanySymbolListValidator::validate(
+///     &company_symbol_list,
+///     DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+/// );
+///
+/// assert_eq!(issues.len(), 2);
+/// assert_eq!(issues[0].kind, CompanySymbolListIssueKind::StopWordCompanyName);
+/// assert_eq!(issues[0].value, "The");
+/// assert_eq!(issues[1].kind, CompanySymbolListIssueKind::DuplicateTickerSymbol);
+/// assert_eq!(issues[1].entry_idx, 1);
+/// ```
+pub struct CompanySymbolListValidator {}
+
+impl CompanySymbolListValidator {
+    /// Validates a company symbol list.
+    ///
+    /// Company names are tokenized with the stop words and Unicode normalization
+    /// of the configuration, so that issues are reported against the same stop
+    /// words the extractor filters.
+    ///
+    /// # Arguments
+    /// * `company_symbol_list` - A reference to the company symbol list.
+    /// * `config` - A reference to the configuration the list is extracted with.
+    ///
+    /// # Returns
+    /// * The issues found, ordered by entry, then by kind (in the order of
+    ///   `CompanySymbolListIssueKind::ALL`).
+    pub fn validate(
+        company_symbol_list: &CompanySymbolList,
+        config: &CompanyTokenProcessorConfig,
+    ) -> Vec<CompanySymbolListIssue> {
+        let text_doc_tokenizer = Tokenizer::text_doc_parser(false)
+            .with_stop_words(&config.stop_words)
+            .with_diacritic_folding(config.fold_diacritics);
+        let verbatim_tokenizer = Tokenizer::verbatim_doc_parser();
+
+        let stop_words: HashSet<String> = config.stop_words.to_uppercase_set();
+
+        // The entries of each ticker symbol, and of each primary company name
+        let mut ticker_symbol_entries: HashMap<&str, Vec<usize>> = HashMap::new();
+        let mut company_name_entries: HashMap<String, Vec<usize>> = HashMap::new();
+        for (entry_idx, company_metadata) in company_symbol_list.iter().enumerate() {
+            ticker_symbol_entries
+                .entry(&company_metadata.ticker_symbol)
+                .or_default()
+                .push(entry_idx);
+
+            if let Some(company_name) = &company_metadata.company_name {
+                company_name_entries
+                    .entry(Self::normalize_name(company_name))
+                    .or_default()
+                    .push(entry_idx);
+            }
+        }
+
+        let mut issues = Vec::new();
+
+        for (entry_idx, company_metadata) in company_symbol_list.iter().enumerate() {
+            let ticker_symbol = &company_metadata.ticker_symbol;
+            let new_issue =
+                |kind, value: &str, related_entry_idx: Option<usize>| CompanySymbolListIssue {
+                    kind,
+                    entry_idx,
+                    ticker_symbol: ticker_symbol.clone(),
+                    value: value.to_string(),
+                    related_entry_idx,
+                    related_ticker_symbol: related_entry_idx.map(|related_entry_idx| {
+                        company_symbol_list[related_entry_idx].ticker_symbol.clone()
+                    }),
+                };
+
+            // Only the later of two overlapping entries is reported
+            if let Some(duplicate_entry_idx) = ticker_symbol_entries[ticker_symbol.as_str()]
+                .iter()
+                .take_while(|other_entry_idx| **other_entry_idx < entry_idx)
+                .find(|other_entry_idx| {
+                    company_metadata
+                        .has_overlapping_validity(&company_symbol_list[**other_entry_idx])
+                })
+            {
+                issues.push(new_issue(
+                    CompanySymbolListIssueKind::DuplicateTickerSymbol,
+                    ticker_symbol,
+                    Some(*duplicate_entry_idx),
+                ));
+            }
+
+            for company_name in company_metadata
+                .company_name
+                .iter()
+                .chain(&company_metadata.alternate_company_names)
+            {
+                if !text_doc_tokenizer
+                    .tokenize(&company_name.to_uppercase())
+                    .is_empty()
+                {
+                    continue;
+                }
+
+                let words: Vec<String> = verbatim_tokenizer
+                    .tokenize(&company_name.to_uppercase())
+                    .iter()
+                    .map(|word| word.chars().filter(|c| c.is_alphanumeric()).collect())
+                    .filter(|word: &String| !word.is_empty())
+                    .collect();
+
+                let kind = if words.iter().any(|word| stop_words.contains(word)) {
+                    CompanySymbolListIssueKind::StopWordCompanyName
+                } else {
+                    CompanySymbolListIssueKind::EmptyCompanyName
+                };
+
+                issues.push(new_issue(kind, company_name, None));
+            }
+
+            for alternate_company_name in &company_metadata.alternate_company_names {
+                let Some(other_entry_idx) = company_name_entries
+                    .get(&Self::normalize_name(alternate_company_name))
+                    .and_then(|entry_idxs| {
+                        entry_idxs.iter().find(|other_entry_idx| {
+                            company_symbol_list[**other_entry_idx].ticker_symbol != *ticker_symbol
+                        })
+                    })
+                else {
+                    continue;
+                };
+
+                issues.push(new_issue(
+                    CompanySymbolListIssueKind::AlternateNameMatchesCompanyName,
+                    alternate_company_name,
+                    Some(*other_entry_idx),
+                ));
+            }
+
+            if stop_words.contains(&ticker_symbol.to_uppercase()) {
+                issues.push(new_issue(
+                    CompanySymbolListIssueKind::StopWordTickerSymbol,
+                    ticker_symbol,
+                    None,
+                ));
+            }
+        }
+
+        issues
+    }
+
+    /// Normalizes a company name for comparison, ignoring case and repeated whitespace.
+    fn normalize_name(company_name: &str) -> String {
+        company_name
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ")
+            .to_uppercase()
+    }
+}
@@ -1,19 +1,32 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::types::{
-    CompanySequenceIndex, CompanySymbolList, TickerSymbol, TickerSymbolTokenId, TokenId,
+    CompanySequenceIndex, CompanySymbolList, TickerSymbol, TickerSymbolTokenId, Token, TokenId,
 };
 
-use crate::{Error, TokenMapper, Tokenizer};
+use crate::config::DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG;
+use crate::utils::normalize_ticker_symbol;
+use crate::utils::normalize_ticker_symbol::normalize_share_class_ticker_symbol;
+use crate::{
+    CompanyMetadata, CompanyTokenProcessorConfig, Error, Market, TokenMapper,
+    TokenSequenceCollision, TokenSequenceCollisionMatch, Tokenizer,
+};
+
+/// The best coverage, and company token sequence, of each ticker symbol which can
+/// be matched by a token sequence.
+type TokenSequenceMatchMap<'a> = HashMap<TickerSymbolTokenId, (f32, &'a [TokenId])>;
 
 pub struct CompanyTokenMapper {
     pub token_mapper: TokenMapper,
     pub ticker_symbol_tokenizer: Tokenizer,
     pub text_doc_tokenizer: Tokenizer,
+    pub cashtag_tokenizer: Tokenizer,
+    pub exchange_prefixed_tokenizer: Tokenizer,
     pub ticker_symbol_map: HashMap<TickerSymbol, TickerSymbolTokenId>,
     pub reverse_ticker_symbol_map: HashMap<TokenId, TickerSymbol>,
     pub company_token_sequences_map: HashMap<TickerSymbolTokenId, Vec<Vec<TokenId>>>,
     pub company_reverse_token_map: HashMap<TokenId, Vec<TickerSymbolTokenId>>,
+    pub company_metadata_map: HashMap<TickerSymbol, CompanyMetadata>,
 }
 
 impl CompanyTokenMapper {
@@ -28,49 +41,183 @@ impl CompanyTokenMapper {
     pub fn new(
         company_symbol_list: &CompanySymbolList,
         is_case_sensitive: bool,
+    ) -> Result<Self, Error> {
+        Self::with_config(
+            company_symbol_list,
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+            is_case_sensitive,
+        )
+    }
+
+    /// Creates a new instance of `CompanyTokenMapper`, with tokenizers, asset type
+    /// filters, market filters, and the as-of date configured by the provided
+    /// configuration.
+    ///
+    /// The tokenizers recognize market-qualified ticker symbols (e.g., "VOD.L")
+    /// for each included market which is present in the company symbol list, and
+    /// the ticker symbol, cashtag, and exchange-prefixed ticker symbol tokenizers
+    /// recognize share class ticker symbols in any of their common formats (e.g.,
+    /// "BRK.B", "BRK/B", or "BRK B" for "BRK-B").
+    ///
+    /// # Arguments
+    /// * `company_symbol_list` - A reference to the list of company symbols.
+    /// * `config` - A reference to the configuration for processing tokens.
+    /// * `is_case_sensitive` - Whether or not the text document should be filtered using case sensitivity.
+    ///
+    /// # Errors
+    /// Returns an error if token ingestion fails.
+    pub fn with_config(
+        company_symbol_list: &CompanySymbolList,
+        config: &CompanyTokenProcessorConfig,
+        is_case_sensitive: bool,
     ) -> Result<Self, Error> {
         let token_mapper = TokenMapper::new();
 
-        let ticker_symbol_tokenizer = Tokenizer::ticker_symbol_parser(is_case_sensitive);
-        let text_doc_tokenizer = Tokenizer::text_doc_parser(is_case_sensitive);
+        let included_company_metadata: Vec<&CompanyMetadata> = company_symbol_list
+            .iter()
+            .filter(|company_metadata| Self::is_included(company_metadata, config))
+            .collect();
+
+        let (
+            ticker_symbol_tokenizer,
+            text_doc_tokenizer,
+            cashtag_tokenizer,
+            exchange_prefixed_tokenizer,
+        ) = Self::build_tokenizers(&included_company_metadata, config, is_case_sensitive);
 
         let mut instance = CompanyTokenMapper {
             token_mapper,
             ticker_symbol_tokenizer,
             text_doc_tokenizer,
+            cashtag_tokenizer,
+            exchange_prefixed_tokenizer,
             ticker_symbol_map: HashMap::with_capacity(company_symbol_list.len()),
             reverse_ticker_symbol_map: HashMap::with_capacity(company_symbol_list.len()),
             company_token_sequences_map: HashMap::with_capacity(company_symbol_list.len()),
             company_reverse_token_map: HashMap::new(),
+            company_metadata_map: HashMap::with_capacity(company_symbol_list.len()),
         };
 
-        instance.ingest_company_tokens(company_symbol_list)?;
+        instance.ingest_company_tokens(company_symbol_list, config)?;
 
         Ok(instance)
     }
 
+    /// Replaces the tokenizers with ones configured by the provided configuration,
+    /// keeping the existing token maps.
+    ///
+    /// The token maps only depend on the entries which were ingested, and the stop
+    /// words and normalization their company names were tokenized with, so this is
+    /// equivalent to rebuilding the mapper, provided that the configuration
+    /// includes the same entries, stop words, and normalization.
+    ///
+    /// # Arguments
+    /// * `config` - A reference to the configuration for processing tokens.
+    /// * `is_case_sensitive` - Whether or not the text document should be filtered using case sensitivity.
+    pub(crate) fn with_tokenizers(
+        mut self,
+        config: &CompanyTokenProcessorConfig,
+        is_case_sensitive: bool,
+    ) -> Self {
+        let included_company_metadata: Vec<&CompanyMetadata> =
+            self.company_metadata_map.values().collect();
+
+        let (
+            ticker_symbol_tokenizer,
+            text_doc_tokenizer,
+            cashtag_tokenizer,
+            exchange_prefixed_tokenizer,
+        ) = Self::build_tokenizers(&included_company_metadata, config, is_case_sensitive);
+
+        self.ticker_symbol_tokenizer = ticker_symbol_tokenizer;
+        self.text_doc_tokenizer = text_doc_tokenizer;
+        self.cashtag_tokenizer = cashtag_tokenizer;
+        self.exchange_prefixed_tokenizer = exchange_prefixed_tokenizer;
+        self
+    }
+
+    /// Builds the ticker symbol, text document, cashtag, and exchange-prefixed
+    /// ticker symbol tokenizers for the included entries of a company symbol list.
+    ///
+    /// # Arguments
+    /// * `included_company_metadata` - The entries which are ingested.
+    /// * `config` - A reference to the configuration for processing tokens.
+    /// * `is_case_sensitive` - Whether or not the text document should be filtered using case sensitivity.
+    fn build_tokenizers(
+        included_company_metadata: &[&CompanyMetadata],
+        config: &CompanyTokenProcessorConfig,
+        is_case_sensitive: bool,
+    ) -> (Tokenizer, Tokenizer, Tokenizer, Tokenizer) {
+        let markets: HashSet<Market> = included_company_metadata
+            .iter()
+            .map(|company_metadata| company_metadata.market)
+            .collect();
+
+        let ticker_symbols = included_company_metadata
+            .iter()
+            .map(|company_metadata| &company_metadata.ticker_symbol);
+
+        let ticker_symbol_tokenizer = Tokenizer::ticker_symbol_parser(is_case_sensitive)
+            .with_min_uppercase_ratio(Some(config.min_ticker_symbol_uppercase_ratio))
+            .with_diacritic_folding(config.fold_diacritics)
+            .with_markets(&markets)
+            .with_share_class_ticker_symbols(ticker_symbols.clone());
+        let text_doc_tokenizer = Tokenizer::text_doc_parser(is_case_sensitive)
+            .with_stop_words(&config.stop_words)
+            .with_diacritic_folding(config.fold_diacritics)
+            .with_markets(&markets);
+        let cashtag_tokenizer = Tokenizer::cashtag_parser()
+            .with_diacritic_folding(config.fold_diacritics)
+            .with_markets(&markets)
+            .with_share_class_ticker_symbols(ticker_symbols.clone());
+        let exchange_prefixed_tokenizer = Tokenizer::exchange_prefixed_parser(is_case_sensitive)
+            .with_min_uppercase_ratio(Some(config.min_ticker_symbol_uppercase_ratio))
+            .with_diacritic_folding(config.fold_diacritics)
+            .with_markets(&markets)
+            .with_share_class_ticker_symbols(ticker_symbols);
+
+        (
+            ticker_symbol_tokenizer,
+            text_doc_tokenizer,
+            cashtag_tokenizer,
+            exchange_prefixed_tokenizer,
+        )
+    }
+
     /// Clears all token maps and associated data from the mapper.
     fn clear(&mut self) {
         self.company_token_sequences_map.clear();
         self.company_reverse_token_map.clear();
         self.ticker_symbol_map.clear();
         self.reverse_ticker_symbol_map.clear();
+        self.company_metadata_map.clear();
     }
 
     /// Ingests tokens from the provided company symbol list into the mapper.
     ///
     /// # Arguments
     /// * `company_symbol_list` - A reference to the list of company symbols.
+    /// * `config` - A reference to the configuration, which determines the asset types,
+    ///   markets, and validity date of the entries to ingest.
     ///
     /// # Errors
     /// Returns an error if the ingestion process fails.
     fn ingest_company_tokens(
         &mut self,
         company_symbol_list: &CompanySymbolList,
+        config: &CompanyTokenProcessorConfig,
     ) -> Result<(), Error> {
         self.clear();
 
-        for (ticker_symbol, company_name, alt_company_names) in company_symbol_list {
+        for company_metadata in company_symbol_list {
+            // Skip asset types, markets, and historical entries which are excluded
+            // by the configuration
+            if !Self::is_included(company_metadata, config) {
+                continue;
+            }
+
+            let ticker_symbol = &company_metadata.ticker_symbol;
+
             let mut all_company_name_token_ids = Vec::new();
 
             // Tokenize the ticker symbol and upsert token IDs
@@ -87,7 +234,7 @@ impl CompanyTokenMapper {
 
             let ticker_symbol_token_id = *self.get_ticker_symbol_token_id(ticker_symbol)?;
 
-            if let Some(company_name) = company_name {
+            if let Some(company_name) = &company_metadata.company_name {
                 let company_name_token_ids = self.process_company_name_tokens(company_name);
                 all_company_name_token_ids.push(company_name_token_ids.clone());
 
@@ -101,7 +248,7 @@ impl CompanyTokenMapper {
             }
 
             // Process alternate company names
-            for alt_company_name in alt_company_names {
+            for alt_company_name in &company_metadata.alternate_company_names {
                 let alt_company_name_token_ids = self.process_company_name_tokens(alt_company_name);
                 all_company_name_token_ids.push(alt_company_name_token_ids.clone());
 
@@ -119,11 +266,28 @@ impl CompanyTokenMapper {
                 .entry(ticker_symbol_token_id)
                 .or_default()
                 .extend(all_company_name_token_ids);
+
+            self.company_metadata_map
+                .insert(ticker_symbol.clone(), company_metadata.clone());
         }
 
         Ok(())
     }
 
+    /// Determines whether an entry of the company symbol list should be ingested.
+    ///
+    /// # Arguments
+    /// * `company_metadata` - A reference to the entry.
+    /// * `config` - A reference to the configuration.
+    pub(crate) fn is_included(
+        company_metadata: &CompanyMetadata,
+        config: &CompanyTokenProcessorConfig,
+    ) -> bool {
+        config.is_asset_type_included(company_metadata.asset_type)
+            && config.is_market_included(company_metadata.market)
+            && company_metadata.is_valid_as_of(config.as_of_date)
+    }
+
     /// Tokenizes the given company name and processes its tokens into unique token IDs.
     ///
     /// # Arguments
@@ -160,9 +324,7 @@ impl CompanyTokenMapper {
     ) -> Result<&TickerSymbol, Error> {
         match self.reverse_ticker_symbol_map.get(token_id) {
             Some(ticker_symbol) => Ok(ticker_symbol),
-            None => Err(Error::ParserError(
-                format!("Could not ticker from token token id: {}", token_id).to_string(),
-            )),
+            None => Err(Error::UnknownTokenIdError(*token_id)),
         }
     }
 
@@ -179,7 +341,7 @@ impl CompanyTokenMapper {
     ) -> Result<&TokenId, Error> {
         match self.ticker_symbol_map.get(ticker_symbol) {
             Some(token_id) => Ok(token_id),
-            None => Err(Error::ParserError("Could not obtain token id".to_string())),
+            None => Err(Error::UnknownTickerSymbolError(ticker_symbol.clone())),
         }
     }
 
@@ -200,4 +362,181 @@ impl CompanyTokenMapper {
             .get(ticker_symbol_token_id)
             .and_then(|seq| seq.get(company_sequence_idx).map(|s| s.len()))
     }
+
+    /// Retrieves the company metadata for the given ticker symbol.
+    ///
+    /// # Arguments
+    /// * `ticker_symbol` - The ticker symbol to look up. The lookup is case insensitive,
+    ///   and accepts any share class format (e.g., "BRK.B" for "BRK-B"). A suffix
+    ///   which is both a share class and a market suffix (e.g., "MKC.V") resolves
+    ///   to the share class if it is in the company symbol list.
+    ///
+    /// # Returns
+    /// The company metadata, if the ticker symbol is in the company symbol list.
+    pub fn get_company_metadata(&self, ticker_symbol: &str) -> Option<&CompanyMetadata> {
+        self.company_metadata_map
+            .get(ticker_symbol)
+            .or_else(|| self.company_metadata_map.get(&ticker_symbol.to_uppercase()))
+            .or_else(|| {
+                self.company_metadata_map
+                    .get(&normalize_share_class_ticker_symbol(ticker_symbol))
+            })
+            .or_else(|| {
+                self.company_metadata_map
+                    .get(&normalize_ticker_symbol(ticker_symbol))
+            })
+    }
+
+    /// Collects the token sequences which can be matched to more than one ticker
+    /// symbol, ranked by how likely they are to cause false positives.
+    ///
+    /// A token sequence can be matched to a ticker symbol if it is the start of
+    /// one of its company name token sequences, and covers at least the given
+    /// fraction of it (e.g., "FIDELITY BLUE CHIP" covers 75% of
+    /// "FIDELITY BLUE CHIP GROWTH").
+    ///
+    /// # Arguments
+    /// * `min_company_token_coverage` - The minimum fraction of a company name's
+    ///   tokens which must be covered, typically the configured
+    ///   `threshold_min_company_token_coverage`.
+    ///
+    /// # Errors
+    /// Returns an error if a token ID or ticker symbol cannot be resolved.
+    ///
+    /// # Returns
+    /// The collisions, in order of descending `ambiguity_score`, then by the
+    /// number of tokens, then by the tokens.
+    pub fn collect_token_sequence_collisions(
+        &self,
+        min_company_token_coverage: f32,
+    ) -> Result<Vec<TokenSequenceCollision>, Error> {
+        // The best coverage of each ticker symbol reachable from each token sequence
+        let mut token_sequence_matches: HashMap<&[TokenId], TokenSequenceMatchMap> = HashMap::new();
+
+        for (ticker_symbol_token_id, company_token_sequences) in &self.company_token_sequences_map {
+            for company_token_sequence in company_token_sequences {
+                for prefix_len in 1..=company_token_sequence.len() {
+                    let company_token_coverage =
+                        prefix_len as f32 / company_token_sequence.len() as f32;
+
+                    if company_token_coverage < min_company_token_coverage {
+                        continue;
+                    }
+
+                    let best_match = token_sequence_matches
+                        .entry(&company_token_sequence[..prefix_len])
+                        .or_default()
+                        .entry(*ticker_symbol_token_id)
+                        .or_insert((company_token_coverage, company_token_sequence));
+
+                    if company_token_coverage > best_match.0 {
+                        *best_match = (company_token_coverage, company_token_sequence);
+                    }
+                }
+            }
+        }
+
+        let get_tokens = |token_ids: &[TokenId]| -> Result<Vec<Token>, Error> {
+            token_ids
+                .iter()
+                .map(|token_id| {
+                    self.token_mapper
+                        .get_token_by_id(*token_id)
+                        .ok_or(Error::UnknownTokenIdError(*token_id))
+                })
+                .collect()
+        };
+
+        let mut collisions = Vec::new();
+
+        for (token_sequence, ticker_symbol_matches) in token_sequence_matches {
+            if ticker_symbol_matches.len() < 2 {
+                continue;
+            }
+
+            let mut matches = ticker_symbol_matches
+                .into_iter()
+                .map(
+                    |(ticker_symbol_token_id, (company_token_coverage, company_token_sequence))| {
+                        Ok(TokenSequenceCollisionMatch {
+                            ticker_symbol: self
+                                .get_ticker_symbol_by_token_id(&ticker_symbol_token_id)?
+                                .clone(),
+                            company_tokens: get_tokens(company_token_sequence)?,
+                            company_token_coverage,
+                        })
+                    },
+                )
+                .collect::<Result<Vec<TokenSequenceCollisionMatch>, Error>>()?;
+
+            matches.sort_by(|a, b| {
+                b.company_token_coverage
+                    .total_cmp(&a.company_token_coverage)
+                    .then_with(|| a.ticker_symbol.cmp(&b.ticker_symbol))
+            });
+
+            let competing_coverage: f32 = matches
+                .iter()
+                .skip(1)
+                .map(|collision_match| collision_match.company_token_coverage)
+                .sum();
+
+            collisions.push(TokenSequenceCollision {
+                tokens: get_tokens(token_sequence)?,
+                matches,
+                ambiguity_score: competing_coverage / token_sequence.len() as f32,
+            });
+        }
+
+        collisions.sort_by(|a, b| {
+            b.ambiguity_score
+                .total_cmp(&a.ambiguity_score)
+                .then_with(|| a.tokens.len().cmp(&b.tokens.len()))
+                .then_with(|| a.tokens.cmp(&b.tokens))
+        });
+
+        Ok(collisions)
+    }
+
+    /// Searches the company symbol list for companies whose name or alternate
+    /// names contain the query.
+    ///
+    /// Results are ranked by how closely a name matches the query (see
+    /// `CompanyMetadata::rank_name_match`), then by the length of the primary
+    /// company name, then by ticker symbol.
+    ///
+    /// # Arguments
+    /// * `query` - The text to search for. The search is case insensitive.
+    ///
+    /// # Returns
+    /// The matching company metadata, best match first.
+    pub fn search_company_metadata(&self, query: &str) -> Vec<&CompanyMetadata> {
+        let lowercased_query = query.trim().to_lowercase();
+
+        let mut ranked_results: Vec<(usize, &CompanyMetadata)> = self
+            .company_metadata_map
+            .values()
+            .filter_map(|company_metadata| {
+                company_metadata
+                    .rank_name_match(&lowercased_query)
+                    .map(|rank| (rank, company_metadata))
+            })
+            .collect();
+
+        ranked_results.sort_by(|(a_rank, a), (b_rank, b)| {
+            a_rank
+                .cmp(b_rank)
+                .then_with(|| {
+                    let a_len = a.company_name.as_ref().map_or(0, |name| name.len());
+                    let b_len = b.company_name.as_ref().map_or(0, |name| name.len());
+                    a_len.cmp(&b_len)
+                })
+                .then_with(|| a.ticker_symbol.cmp(&b.ticker_symbol))
+        });
+
+        ranked_results
+            .into_iter()
+            .map(|(_, company_metadata)| company_metadata)
+            .collect()
+    }
 }
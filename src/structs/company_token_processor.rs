@@ -1,27 +1,51 @@
 use crate::types::{
-    CompanySequenceIndex, CompanySymbolList, TickerSymbol, TickerSymbolFrequencyMap,
-    TickerSymbolTokenId, Token, TokenId,
+    CompanySequenceIndex, CompanySymbolList, QueryTokenIndex, TickerSymbol,
+    TickerSymbolFrequencyMap, TickerSymbolTokenId, TokenId,
 };
 use crate::utils::{count_ticker_symbol_frequencies, dedup_vector};
-use crate::{CompanyTokenMapper, Error, TokenParityState, TokenRangeState};
+use crate::{
+    CompanyTokenMapper, CompanyTokenProcessorConfig, Error, ExtractionTrace, Market,
+    TickerSymbolMention, TickerSymbolMentionKind, TickerSymbolResult, TickerSymbolTrace,
+    TickerSymbolTraceOutcome, TokenParityState, TokenRangeState, TokenSpan,
+};
 
 use log::info;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
 
 type PotentialTokenSequenceMap =
     HashMap<TickerSymbolTokenId, Vec<(CompanySequenceIndex, Vec<TokenId>)>>;
 
-pub struct CompanyTokenProcessorConfig {
-    pub threshold_ratio_exact_matches: f32,
-    pub threshold_min_company_token_coverage: f32,
+type QueryTokenMatches = Vec<(TokenId, TokenSpan)>;
+
+/// The intermediate results of running the token matching pipeline against a
+/// text document.
+struct TextDocAnalysis {
+    /// The spans of the filtered text document tokens, indexed by query token index.
+    query_text_doc_token_spans: Vec<TokenSpan>,
+
+    /// The ticker symbol token IDs which survived filtering, paired with their spans.
+    query_ticker_symbol_token_matches: QueryTokenMatches,
+
+    /// The cashtag token IDs, paired with their spans.
+    query_cashtag_token_matches: QueryTokenMatches,
+
+    /// The exchange-prefixed ticker symbol token IDs, paired with their spans.
+    query_exchange_prefixed_token_matches: QueryTokenMatches,
+
+    /// The top scoring token range states.
+    top_range_states: Vec<TokenRangeState>,
+
+    /// The combined ticker symbol frequencies.
+    ticker_symbol_frequency_map: TickerSymbolFrequencyMap,
 }
 
-pub struct CompanyTokenProcessor<'a> {
-    config: &'a CompanyTokenProcessorConfig,
+pub struct CompanyTokenProcessor {
+    config: CompanyTokenProcessorConfig,
     company_token_mapper: CompanyTokenMapper,
 }
 
-impl<'a> CompanyTokenProcessor<'a> {
+impl CompanyTokenProcessor {
     /// Creates a new `CompanyTokenProcessor` with the given configuration and company symbol list.
     ///
     /// # Arguments
@@ -30,20 +54,51 @@ impl<'a> CompanyTokenProcessor<'a> {
     /// * `is_case_sensitive` - Whether or not the text document should be filtered using case sensitivity.
     ///
     /// # Errors
-    /// Returns an error if initialization fails.
+    /// Returns an error if the configuration is invalid, or if initialization fails.
     pub fn new(
-        config: &'a CompanyTokenProcessorConfig,
-        company_symbol_list: &'a CompanySymbolList,
+        config: &CompanyTokenProcessorConfig,
+        company_symbol_list: &CompanySymbolList,
         is_case_sensitive: bool,
     ) -> Result<Self, Error> {
-        let company_token_mapper = CompanyTokenMapper::new(company_symbol_list, is_case_sensitive)?;
+        config.validate()?;
+
+        let company_token_mapper =
+            CompanyTokenMapper::with_config(company_symbol_list, config, is_case_sensitive)?;
+
+        Self::with_company_token_mapper(config, company_token_mapper)
+    }
+
+    /// Creates a new `CompanyTokenProcessor` from a company token mapper which has
+    /// already been built for the provided configuration.
+    ///
+    /// # Arguments
+    /// * `config` - A reference to the configuration for processing tokens.
+    /// * `company_token_mapper` - The company token mapper.
+    ///
+    /// # Errors
+    /// Returns `Error::ConfigError` if the configuration is invalid.
+    pub(crate) fn with_company_token_mapper(
+        config: &CompanyTokenProcessorConfig,
+        company_token_mapper: CompanyTokenMapper,
+    ) -> Result<Self, Error> {
+        config.validate()?;
 
         Ok(CompanyTokenProcessor {
-            config,
+            config: config.clone(),
             company_token_mapper,
         })
     }
 
+    /// Returns the configuration used for processing tokens.
+    pub fn config(&self) -> &CompanyTokenProcessorConfig {
+        &self.config
+    }
+
+    /// Returns the company token mapper, which holds the indexed company symbol list.
+    pub fn company_token_mapper(&self) -> &CompanyTokenMapper {
+        &self.company_token_mapper
+    }
+
     /// Processes a text document and extracts ticker symbols with their frequencies.
     ///
     /// # Arguments
@@ -51,7 +106,130 @@ impl<'a> CompanyTokenProcessor<'a> {
     ///
     /// # Errors
     /// Returns an error if the processing fails.
-    pub fn process_text_doc(&mut self, text: &str) -> Result<TickerSymbolFrequencyMap, Error> {
+    pub fn process_text_doc(&self, text: &str) -> Result<TickerSymbolFrequencyMap, Error> {
+        let text_doc_analysis = self.analyze_text_doc(text, None)?;
+
+        Ok(text_doc_analysis.ticker_symbol_frequency_map)
+    }
+
+    /// Processes a text document and extracts each individual mention of a ticker
+    /// symbol, along with its location in the text.
+    ///
+    /// Mentions are ordered by their position in the text. Every ticker symbol
+    /// which would be returned by `process_text_doc` is represented by at least
+    /// one mention.
+    ///
+    /// Note: Bare ticker symbol mentions are reported even if the same company
+    /// was also referenced by name, so the number of mentions for a ticker symbol
+    /// may exceed its frequency count.
+    ///
+    /// # Arguments
+    /// * `text` - The text document to process.
+    ///
+    /// # Errors
+    /// Returns an error if the processing fails.
+    pub fn process_text_doc_mentions(&self, text: &str) -> Result<Vec<TickerSymbolMention>, Error> {
+        let text_doc_analysis = self.analyze_text_doc(text, None)?;
+
+        self.collect_ticker_symbol_mentions(text, &text_doc_analysis)
+    }
+
+    /// Processes a text document and extracts ticker symbols, along with their
+    /// frequencies and confidence scores.
+    ///
+    /// The confidence of each ticker symbol combines the confidence of each of its
+    /// mentions, such that `confidence = 1 - (1 - c1) * (1 - c2) * ...`. A mention's
+    /// confidence is derived from its company token coverage, its range score,
+    /// whether it matched on the ticker symbol, and whether the same text also
+    /// matched other ticker symbols.
+    ///
+    /// Results are ordered by confidence (descending), then by ticker symbol.
+    ///
+    /// # Arguments
+    /// * `text` - The text document to process.
+    ///
+    /// # Errors
+    /// Returns an error if the processing fails.
+    pub fn process_text_doc_results(&self, text: &str) -> Result<Vec<TickerSymbolResult>, Error> {
+        let text_doc_analysis = self.analyze_text_doc(text, None)?;
+        let mentions = self.collect_ticker_symbol_mentions(text, &text_doc_analysis)?;
+
+        let mut results: Vec<TickerSymbolResult> = text_doc_analysis
+            .ticker_symbol_frequency_map
+            .iter()
+            .map(|(ticker_symbol, frequency)| {
+                let ticker_symbol_mentions: Vec<&TickerSymbolMention> = mentions
+                    .iter()
+                    .filter(|mention| &mention.ticker_symbol == ticker_symbol)
+                    .collect();
+
+                let inverse_confidence = ticker_symbol_mentions
+                    .iter()
+                    .fold(1.0, |acc, mention| acc * (1.0 - mention.confidence));
+
+                let company_metadata = self
+                    .company_token_mapper
+                    .get_company_metadata(ticker_symbol);
+
+                TickerSymbolResult {
+                    ticker_symbol: ticker_symbol.clone(),
+                    frequency: *frequency,
+                    mention_count: ticker_symbol_mentions.len(),
+                    confidence: (1.0 - inverse_confidence).clamp(0.0, 1.0),
+                    asset_type: company_metadata
+                        .and_then(|company_metadata| company_metadata.asset_type),
+                    market: company_metadata
+                        .map_or_else(Market::default, |company_metadata| company_metadata.market),
+                    successor_ticker_symbol: company_metadata.and_then(|company_metadata| {
+                        company_metadata.successor_ticker_symbol.clone()
+                    }),
+                }
+            })
+            .collect();
+
+        results.sort_by(|a, b| {
+            b.confidence
+                .partial_cmp(&a.confidence)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.ticker_symbol.cmp(&b.ticker_symbol))
+        });
+
+        Ok(results)
+    }
+
+    /// Processes a text document while recording the decisions made at each stage
+    /// of the token matching pipeline.
+    ///
+    /// This is slower than `process_text_doc` and is intended for diagnosing
+    /// missing or spurious ticker symbols.
+    ///
+    /// # Arguments
+    /// * `text` - The text document to process.
+    ///
+    /// # Errors
+    /// Returns an error if the processing fails.
+    pub fn trace_text_doc(&self, text: &str) -> Result<ExtractionTrace, Error> {
+        let mut trace = ExtractionTrace::default();
+
+        let text_doc_analysis = self.analyze_text_doc(text, Some(&mut trace))?;
+        trace.ticker_symbol_frequency_map = text_doc_analysis.ticker_symbol_frequency_map;
+
+        Ok(trace)
+    }
+
+    /// Runs the token matching pipeline against a text document.
+    ///
+    /// # Arguments
+    /// * `text` - The text document to process.
+    /// * `trace` - If provided, records the decisions made at each stage.
+    ///
+    /// # Errors
+    /// Returns an error if the processing fails.
+    fn analyze_text_doc(
+        &self,
+        text: &str,
+        mut trace: Option<&mut ExtractionTrace>,
+    ) -> Result<TextDocAnalysis, Error> {
         // Tokenize the input text
         info!("Tokenizing...");
 
@@ -60,18 +238,106 @@ impl<'a> CompanyTokenProcessor<'a> {
         let ticker_symbol_tokens_pre_filtered = self
             .company_token_mapper
             .ticker_symbol_tokenizer
-            .tokenize(text);
+            .tokenize_with_spans(text);
 
-        let text_doc_tokens_pre_filtered =
-            self.company_token_mapper.text_doc_tokenizer.tokenize(text);
+        let text_doc_tokens_pre_filtered = self
+            .company_token_mapper
+            .text_doc_tokenizer
+            .tokenize_with_spans(text);
+
+        // Cashtags require a dollar sign, so most documents can skip them entirely
+        let cashtag_tokens_pre_filtered = if text.contains('$') {
+            self.company_token_mapper
+                .cashtag_tokenizer
+                .tokenize_with_spans(text)
+        } else {
+            Vec::new()
+        };
+
+        // Likewise, exchange-prefixed ticker symbols require a colon
+        let exchange_prefixed_tokens_pre_filtered = if text.contains(':') {
+            self.company_token_mapper
+                .exchange_prefixed_tokenizer
+                .tokenize_with_spans(text)
+        } else {
+            Vec::new()
+        };
 
         info!("Gathering filtered tokens...");
-        let (query_text_doc_token_ids, mut query_ticker_symbol_token_ids) = self
+        let (mut query_text_doc_token_matches, mut query_ticker_symbol_token_matches) = self
             .get_filtered_query_token_ids(
                 &text_doc_tokens_pre_filtered,
                 &ticker_symbol_tokens_pre_filtered,
             );
 
+        let mut query_cashtag_token_matches =
+            self.get_filtered_ticker_symbol_token_ids(&cashtag_tokens_pre_filtered);
+
+        let query_exchange_prefixed_token_matches =
+            self.get_filtered_ticker_symbol_token_ids(&exchange_prefixed_tokens_pre_filtered);
+
+        // The spans of exchange-prefixed ticker symbols include the exchange name, so
+        // any token within them (e.g., "NASDAQ" itself) is not matched separately
+        let exchange_prefixed_byte_ranges: Vec<&Range<usize>> =
+            query_exchange_prefixed_token_matches
+                .iter()
+                .map(|(_, token_span)| &token_span.byte_range)
+                .collect();
+        if !exchange_prefixed_byte_ranges.is_empty() {
+            let is_outside_exchange_prefixed = |(_, token_span): &(TokenId, TokenSpan)| {
+                !exchange_prefixed_byte_ranges.iter().any(|byte_range| {
+                    token_span.byte_range.start < byte_range.end
+                        && byte_range.start < token_span.byte_range.end
+                })
+            };
+
+            query_text_doc_token_matches.retain(is_outside_exchange_prefixed);
+            query_ticker_symbol_token_matches.retain(is_outside_exchange_prefixed);
+            query_cashtag_token_matches.retain(is_outside_exchange_prefixed);
+        }
+
+        // Cashtags are handled separately, so their tokens are not also matched as
+        // company names or bare ticker symbols
+        let cashtag_byte_ranges: HashSet<&Range<usize>> = query_cashtag_token_matches
+            .iter()
+            .map(|(_, token_span)| &token_span.byte_range)
+            .collect();
+        if !cashtag_byte_ranges.is_empty() {
+            query_text_doc_token_matches
+                .retain(|(_, token_span)| !cashtag_byte_ranges.contains(&token_span.byte_range));
+            query_ticker_symbol_token_matches
+                .retain(|(_, token_span)| !cashtag_byte_ranges.contains(&token_span.byte_range));
+        }
+
+        let query_text_doc_token_ids: Vec<TokenId> = query_text_doc_token_matches
+            .iter()
+            .map(|(token_id, _)| *token_id)
+            .collect();
+
+        if let Some(trace) = trace.as_deref_mut() {
+            trace.text_doc_tokens = text_doc_tokens_pre_filtered.clone();
+            trace.ticker_symbol_tokens = ticker_symbol_tokens_pre_filtered.clone();
+            trace.cashtag_tokens = cashtag_tokens_pre_filtered.clone();
+            trace.exchange_prefixed_tokens = exchange_prefixed_tokens_pre_filtered.clone();
+            trace.query_text_doc_token_ids = query_text_doc_token_ids.clone();
+            trace.query_text_doc_token_spans = query_text_doc_token_matches
+                .iter()
+                .map(|(_, token_span)| token_span.clone())
+                .collect();
+            trace.query_ticker_symbol_token_ids = query_ticker_symbol_token_matches
+                .iter()
+                .map(|(token_id, _)| *token_id)
+                .collect();
+            trace.query_cashtag_token_ids = query_cashtag_token_matches
+                .iter()
+                .map(|(token_id, _)| *token_id)
+                .collect();
+            trace.query_exchange_prefixed_token_ids = query_exchange_prefixed_token_matches
+                .iter()
+                .map(|(token_id, _)| *token_id)
+                .collect();
+        }
+
         // Identify token ID sequences which start with the first token of a company token sequence
         info!("Identifying token ID sequences...");
         let potential_token_id_sequences =
@@ -98,6 +364,19 @@ impl<'a> CompanyTokenProcessor<'a> {
             &mut token_range_states,
         );
 
+        if let Some(trace) = trace.as_deref_mut() {
+            trace.token_parity_states = token_parity_states.clone();
+            trace.threshold_min_company_token_coverage =
+                self.config.threshold_min_company_token_coverage;
+            trace.discarded_token_range_states = token_range_states
+                .iter()
+                .filter(|state| {
+                    state.company_token_coverage < self.config.threshold_min_company_token_coverage
+                })
+                .cloned()
+                .collect();
+        }
+
         // Discard token range states which do not meet minimum threshold
         token_range_states.retain(|state| {
             state.company_token_coverage >= self.config.threshold_min_company_token_coverage
@@ -116,26 +395,31 @@ impl<'a> CompanyTokenProcessor<'a> {
         let ratio_exact_matches =
             TokenRangeState::calc_exact_ticker_symbol_match_ratio(&top_range_states);
 
+        // Retain the bare ticker symbols before they are filtered, so their outcomes can be traced
+        let traced_query_ticker_symbol_token_matches = trace
+            .is_some()
+            .then(|| query_ticker_symbol_token_matches.clone());
+
         // Clear exact ticker symbol matches if ratio of exact matches is less than configured minimum
-        if ratio_exact_matches < self.config.threshold_ratio_exact_matches {
-            query_ticker_symbol_token_ids = vec![];
+        let is_below_ratio_exact_matches =
+            ratio_exact_matches < self.config.threshold_ratio_exact_matches;
+        if is_below_ratio_exact_matches {
+            query_ticker_symbol_token_matches = vec![];
         }
 
         // Keep track of number of occurrences, per extracted symbol, for context stats
         let text_doc_ticker_frequencies =
             TokenRangeState::count_token_range_ticker_symbol_frequencies(&top_range_states);
 
-        let query_ticker_symbols: Vec<&TickerSymbol> = query_ticker_symbol_token_ids
+        // Cashtags and exchange-prefixed ticker symbols are always kept, regardless
+        // of the ratio of exact matches
+        let query_ticker_symbols: Vec<&TickerSymbol> = query_ticker_symbol_token_matches
             .iter()
-            .map(|token_id| {
+            .chain(&query_cashtag_token_matches)
+            .chain(&query_exchange_prefixed_token_matches)
+            .map(|(token_id, _)| {
                 self.company_token_mapper
                     .get_ticker_symbol_by_token_id(token_id)
-                    .map_err(|e| {
-                        crate::Error::ParserError(format!(
-                            "Failed to fetch token ID {:?}: {:?}",
-                            token_id, e
-                        ))
-                    })
             })
             .collect::<Result<Vec<&TickerSymbol>, _>>()?;
 
@@ -166,7 +450,260 @@ impl<'a> CompanyTokenProcessor<'a> {
             query_ticker_frequencies.clone(),
         ]);
 
-        Ok(combined_ticker_frequencies)
+        if let Some(trace) = trace {
+            trace.token_range_states = token_range_states;
+            trace.top_range_states = top_range_states.clone();
+            trace.ratio_exact_matches = ratio_exact_matches;
+            trace.threshold_ratio_exact_matches = self.config.threshold_ratio_exact_matches;
+            trace.ticker_symbol_traces = self.collect_ticker_symbol_traces(
+                traced_query_ticker_symbol_token_matches.unwrap_or_default(),
+                is_below_ratio_exact_matches,
+                &text_doc_ticker_frequencies,
+                &query_ticker_frequencies,
+            )?;
+            trace.cashtag_traces = self.collect_ticker_symbol_traces(
+                query_cashtag_token_matches.clone(),
+                false,
+                &text_doc_ticker_frequencies,
+                &query_ticker_frequencies,
+            )?;
+            trace.exchange_prefixed_traces = self.collect_ticker_symbol_traces(
+                query_exchange_prefixed_token_matches.clone(),
+                false,
+                &text_doc_ticker_frequencies,
+                &query_ticker_frequencies,
+            )?;
+        }
+
+        Ok(TextDocAnalysis {
+            query_text_doc_token_spans: query_text_doc_token_matches
+                .into_iter()
+                .map(|(_, token_span)| token_span)
+                .collect(),
+            query_ticker_symbol_token_matches,
+            query_cashtag_token_matches,
+            query_exchange_prefixed_token_matches,
+            top_range_states,
+            ticker_symbol_frequency_map: combined_ticker_frequencies,
+        })
+    }
+
+    /// Determines the outcome of each bare ticker symbol, cashtag, or
+    /// exchange-prefixed ticker symbol found in a text document.
+    ///
+    /// # Arguments
+    /// * `query_ticker_symbol_token_matches` - The bare ticker symbol tokens, before
+    ///   the exact match ratio was applied, or the cashtag or exchange-prefixed tokens.
+    /// * `is_below_ratio_exact_matches` - Whether the exact match ratio was below the
+    ///   configured threshold (never the case for cashtags or exchange-prefixed
+    ///   ticker symbols, which are always kept).
+    /// * `text_doc_ticker_frequencies` - The frequencies of ticker symbols matched by company name.
+    /// * `query_ticker_frequencies` - The adjusted frequencies of bare ticker symbols.
+    ///
+    /// # Errors
+    /// Returns an error if a ticker symbol cannot be resolved from its token ID.
+    fn collect_ticker_symbol_traces(
+        &self,
+        query_ticker_symbol_token_matches: QueryTokenMatches,
+        is_below_ratio_exact_matches: bool,
+        text_doc_ticker_frequencies: &TickerSymbolFrequencyMap,
+        query_ticker_frequencies: &TickerSymbolFrequencyMap,
+    ) -> Result<Vec<TickerSymbolTrace>, Error> {
+        let mut ticker_symbol_traces: Vec<TickerSymbolTrace> = Vec::new();
+
+        for (token_id, token_span) in query_ticker_symbol_token_matches {
+            let ticker_symbol = self
+                .company_token_mapper
+                .get_ticker_symbol_by_token_id(&token_id)?;
+
+            // Group occurrences of the same ticker symbol
+            if let Some(ticker_symbol_trace) = ticker_symbol_traces
+                .iter_mut()
+                .find(|ticker_symbol_trace| &ticker_symbol_trace.ticker_symbol == ticker_symbol)
+            {
+                ticker_symbol_trace.token_spans.push(token_span);
+                continue;
+            }
+
+            let frequency = query_ticker_frequencies
+                .get(ticker_symbol)
+                .copied()
+                .unwrap_or(0);
+
+            let outcome = if is_below_ratio_exact_matches {
+                TickerSymbolTraceOutcome::DroppedByExactMatchRatio
+            } else if text_doc_ticker_frequencies.contains_key(ticker_symbol) {
+                TickerSymbolTraceOutcome::MatchedByCompanyName
+            } else if frequency == 0 {
+                TickerSymbolTraceOutcome::AbsorbedByCompanyNameRange
+            } else {
+                TickerSymbolTraceOutcome::Kept
+            };
+
+            ticker_symbol_traces.push(TickerSymbolTrace {
+                ticker_symbol: ticker_symbol.clone(),
+                token_spans: vec![token_span],
+                frequency,
+                outcome,
+            });
+        }
+
+        Ok(ticker_symbol_traces)
+    }
+
+    /// Collects the individual ticker symbol mentions from an analyzed text document.
+    ///
+    /// # Arguments
+    /// * `text` - The text document which was analyzed.
+    /// * `text_doc_analysis` - The analysis of the text document.
+    ///
+    /// # Errors
+    /// Returns an error if a ticker symbol cannot be resolved from its token ID.
+    fn collect_ticker_symbol_mentions(
+        &self,
+        text: &str,
+        text_doc_analysis: &TextDocAnalysis,
+    ) -> Result<Vec<TickerSymbolMention>, Error> {
+        let mut mentions: Vec<TickerSymbolMention> = Vec::new();
+
+        // Top range states are repeated for each query token they cover, so they
+        // are deduplicated the same way they are when counting frequencies
+        let mut seen_range_states: HashSet<(&TickerSymbol, &Vec<QueryTokenIndex>)> = HashSet::new();
+        let unique_top_range_states: Vec<&TokenRangeState> = text_doc_analysis
+            .top_range_states
+            .iter()
+            .filter(|range_state| {
+                seen_range_states
+                    .insert((&range_state.ticker_symbol, &range_state.query_token_indices))
+            })
+            .collect();
+
+        // Ranges which are tied between multiple ticker symbols are ambiguous, so
+        // their confidence is split between them
+        let mut range_ticker_symbol_counts: HashMap<&Vec<QueryTokenIndex>, usize> = HashMap::new();
+        for range_state in &unique_top_range_states {
+            *range_ticker_symbol_counts
+                .entry(&range_state.query_token_indices)
+                .or_insert(0) += 1;
+        }
+
+        for range_state in unique_top_range_states {
+            let ambiguity_count = range_ticker_symbol_counts
+                .get(&range_state.query_token_indices)
+                .copied()
+                .unwrap_or(1);
+
+            let (Some(first_query_token_idx), Some(last_query_token_idx)) = (
+                range_state.query_token_indices.first(),
+                range_state.query_token_indices.last(),
+            ) else {
+                continue;
+            };
+
+            let first_token_span =
+                &text_doc_analysis.query_text_doc_token_spans[*first_query_token_idx];
+            let last_token_span =
+                &text_doc_analysis.query_text_doc_token_spans[*last_query_token_idx];
+
+            let byte_range = first_token_span.byte_range.start..last_token_span.byte_range.end;
+            let char_range = first_token_span.char_range.start..last_token_span.char_range.end;
+
+            let kind = if range_state.is_matched_on_ticker_symbol == Some(true) {
+                TickerSymbolMentionKind::TickerSymbol
+            } else {
+                TickerSymbolMentionKind::CompanyName
+            };
+
+            mentions.push(TickerSymbolMention {
+                ticker_symbol: range_state.ticker_symbol.clone(),
+                text: text[byte_range.clone()].to_string(),
+                byte_range,
+                char_range,
+                kind,
+                confidence: range_state
+                    .calc_confidence(self.config.ticker_symbol_mention_confidence)
+                    / ambiguity_count as f32,
+            });
+        }
+
+        // Bare ticker symbols which overlap an existing mention are part of a
+        // company name match and are not reported separately
+        let company_name_byte_ranges: Vec<Range<usize>> = mentions
+            .iter()
+            .map(|mention| mention.byte_range.clone())
+            .collect();
+
+        for (token_id, token_span) in &text_doc_analysis.query_ticker_symbol_token_matches {
+            let ticker_symbol = self
+                .company_token_mapper
+                .get_ticker_symbol_by_token_id(token_id)?;
+
+            if !text_doc_analysis
+                .ticker_symbol_frequency_map
+                .contains_key(ticker_symbol)
+            {
+                continue;
+            }
+
+            if company_name_byte_ranges
+                .iter()
+                .any(|company_name_byte_range| {
+                    company_name_byte_range.start < token_span.byte_range.end
+                        && token_span.byte_range.start < company_name_byte_range.end
+                })
+            {
+                continue;
+            }
+
+            mentions.push(TickerSymbolMention {
+                ticker_symbol: ticker_symbol.clone(),
+                byte_range: token_span.byte_range.clone(),
+                char_range: token_span.char_range.clone(),
+                text: token_span.surface.clone(),
+                kind: TickerSymbolMentionKind::TickerSymbol,
+                confidence: self.config.ticker_symbol_mention_confidence,
+            });
+        }
+
+        for (token_id, token_span) in &text_doc_analysis.query_cashtag_token_matches {
+            let ticker_symbol = self
+                .company_token_mapper
+                .get_ticker_symbol_by_token_id(token_id)?;
+
+            mentions.push(TickerSymbolMention {
+                ticker_symbol: ticker_symbol.clone(),
+                byte_range: token_span.byte_range.clone(),
+                char_range: token_span.char_range.clone(),
+                text: token_span.surface.clone(),
+                kind: TickerSymbolMentionKind::Cashtag,
+                confidence: self.config.cashtag_mention_confidence,
+            });
+        }
+
+        for (token_id, token_span) in &text_doc_analysis.query_exchange_prefixed_token_matches {
+            let ticker_symbol = self
+                .company_token_mapper
+                .get_ticker_symbol_by_token_id(token_id)?;
+
+            mentions.push(TickerSymbolMention {
+                ticker_symbol: ticker_symbol.clone(),
+                byte_range: token_span.byte_range.clone(),
+                char_range: token_span.char_range.clone(),
+                text: token_span.surface.clone(),
+                kind: TickerSymbolMentionKind::ExchangePrefixed,
+                confidence: self.config.exchange_prefixed_mention_confidence,
+            });
+        }
+
+        mentions.sort_by(|a, b| {
+            (a.byte_range.start, a.byte_range.end, &a.ticker_symbol).cmp(&(
+                b.byte_range.start,
+                b.byte_range.end,
+                &b.ticker_symbol,
+            ))
+        });
+
+        Ok(mentions)
     }
 
     /// Reduces ticker symbol frequency counts based on matches in token range states.
@@ -248,36 +785,60 @@ impl<'a> CompanyTokenProcessor<'a> {
     /// Retrieves filtered token IDs for both text documents and ticker symbols.
     ///
     /// # Arguments
-    /// * `text_doc_tokens` - Tokens from the text document.
-    /// * `ticker_symbol_tokens` - Tokens from the ticker symbols.
+    /// * `text_doc_tokens` - Token spans from the text document.
+    /// * `ticker_symbol_tokens` - Token spans from the ticker symbols.
     ///
     /// # Returns
-    /// A tuple containing vectors of token IDs.
+    /// A tuple containing vectors of token IDs, paired with the spans of the
+    /// tokens they were sourced from.
     fn get_filtered_query_token_ids(
         &self,
-        text_doc_tokens: &[Token],
-        ticker_symbol_tokens: &[Token],
-    ) -> (Vec<TokenId>, Vec<TokenId>) {
+        text_doc_tokens: &[TokenSpan],
+        ticker_symbol_tokens: &[TokenSpan],
+    ) -> (QueryTokenMatches, QueryTokenMatches) {
         // Get the filtered token IDs (IDs present in the TokenMapper)
-        let query_text_doc_token_ids = self
-            .company_token_mapper
-            .token_mapper
-            .get_filtered_token_ids(text_doc_tokens.iter().map(|s| s.as_str()).collect());
+        let query_text_doc_token_ids = text_doc_tokens
+            .iter()
+            .filter_map(|token_span| {
+                self.company_token_mapper
+                    .token_mapper
+                    .get_token_id(&token_span.token)
+                    .map(|token_id| (token_id, token_span.clone()))
+            })
+            .collect();
 
-        let query_ticker_symbol_token_ids = self
-            .company_token_mapper
-            .token_mapper
-            .get_filtered_token_ids(ticker_symbol_tokens.iter().map(|s| s.as_str()).collect())
-            .into_iter()
+        let query_ticker_symbol_token_ids =
+            self.get_filtered_ticker_symbol_token_ids(ticker_symbol_tokens);
+
+        (query_text_doc_token_ids, query_ticker_symbol_token_ids)
+    }
+
+    /// Retrieves the token IDs of the tokens which are known ticker symbols.
+    ///
+    /// # Arguments
+    /// * `ticker_symbol_tokens` - Token spans of potential ticker symbols.
+    ///
+    /// # Returns
+    /// The token IDs, paired with the spans of the tokens they were sourced from.
+    fn get_filtered_ticker_symbol_token_ids(
+        &self,
+        ticker_symbol_tokens: &[TokenSpan],
+    ) -> QueryTokenMatches {
+        ticker_symbol_tokens
+            .iter()
+            .filter_map(|token_span| {
+                self.company_token_mapper
+                    .token_mapper
+                    .get_token_id(&token_span.token)
+                    .map(|token_id| (token_id, token_span.clone()))
+            })
             // Filter to ticker symbol tokens
-            .filter(|token_id| {
+            .filter(|(token_id, _)| {
                 self.company_token_mapper
                     .company_token_sequences_map
                     .contains_key(token_id)
             })
-            .collect();
-
-        (query_text_doc_token_ids, query_ticker_symbol_token_ids)
+            .collect()
     }
 
     /// Identifies potential token sequences from the query tokens.
@@ -0,0 +1,302 @@
+use crate::config::DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG;
+use crate::{AssetType, Date, Error, Market, StopWordPreset, StopWords};
+
+/// Configuration for processing text documents with a `CompanyTokenProcessor`.
+///
+/// Use `CompanyTokenProcessorConfig::builder` to construct a validated configuration,
+/// starting from the defaults.
+///
+/// # Example
+/// ```
+/// use ticker_sniffer::CompanyTokenProcessorConfig;
+///
+/// let config = CompanyTokenProcessorConfig::builder()
+///     .threshold_min_company_token_coverage(0.75)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(config.threshold_min_company_token_coverage, 0.75);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompanyTokenProcessorConfig {
+    /// The minimum ratio of top company name matches which must have matched on
+    /// the ticker symbol itself for bare ticker symbols (e.g., "AAPL") to be kept.
+    pub threshold_ratio_exact_matches: f32,
+
+    /// The minimum ratio of a company name's tokens which must be matched for
+    /// the company name to be considered a match.
+    pub threshold_min_company_token_coverage: f32,
+
+    /// The minimum ratio of uppercase letters a token must have to be considered
+    /// a ticker symbol, when filtering with case sensitivity.
+    pub min_ticker_symbol_uppercase_ratio: f32,
+
+    /// The confidence assigned to a bare ticker symbol mention which survived the
+    /// exact match ratio filter.
+    pub ticker_symbol_mention_confidence: f32,
+
+    /// The confidence assigned to a cashtag mention (e.g., "$AAPL"). Cashtags are
+    /// always kept, regardless of the exact match ratio.
+    pub cashtag_mention_confidence: f32,
+
+    /// The confidence assigned to an exchange-prefixed ticker symbol mention
+    /// (e.g., "NASDAQ: AAPL"). These are always kept, regardless of the exact
+    /// match ratio.
+    pub exchange_prefixed_mention_confidence: f32,
+
+    /// If set, only ticker symbols with one of these asset types are matched.
+    pub included_asset_types: Option<Vec<AssetType>>,
+
+    /// Ticker symbols with any of these asset types are never matched.
+    pub excluded_asset_types: Vec<AssetType>,
+
+    /// If set, only ticker symbols listed on one of these markets are matched.
+    /// Otherwise, every market in the company symbol list is active.
+    pub included_markets: Option<Vec<Market>>,
+
+    /// The date text documents should be interpreted as of, which determines the
+    /// ticker symbols and company names which are matched (e.g., "Facebook" was
+    /// "FB" in 2019). If not set, only current entries are matched.
+    pub as_of_date: Option<Date>,
+
+    /// The stop words which are filtered from text documents and company names.
+    /// Company names are tokenized with the same stop words, so changing them
+    /// rebuilds the token maps of the embedded symbol index.
+    pub stop_words: StopWords,
+
+    /// Whether diacritics are removed from letters, so that accented and
+    /// unaccented spellings match (e.g., "Nestle" matches "Nestlé"). Company names
+    /// are normalized the same way, so enabling this rebuilds the token maps of
+    /// the embedded symbol index.
+    pub fold_diacritics: bool,
+}
+
+impl CompanyTokenProcessorConfig {
+    /// Creates a builder, initialized with the default configuration.
+    pub fn builder() -> CompanyTokenProcessorConfigBuilder {
+        CompanyTokenProcessorConfigBuilder::default()
+    }
+
+    /// Validates that each ratio is within its allowed range, and that each stop
+    /// word is a single word.
+    ///
+    /// # Errors
+    /// Returns `Error::ConfigError` if any ratio is not a finite number between
+    /// `0.0` and `1.0`, or if any added or removed stop word is not a single word.
+    pub fn validate(&self) -> Result<(), Error> {
+        let ratios = [
+            (
+                "threshold_ratio_exact_matches",
+                self.threshold_ratio_exact_matches,
+            ),
+            (
+                "threshold_min_company_token_coverage",
+                self.threshold_min_company_token_coverage,
+            ),
+            (
+                "min_ticker_symbol_uppercase_ratio",
+                self.min_ticker_symbol_uppercase_ratio,
+            ),
+            (
+                "ticker_symbol_mention_confidence",
+                self.ticker_symbol_mention_confidence,
+            ),
+            (
+                "cashtag_mention_confidence",
+                self.cashtag_mention_confidence,
+            ),
+            (
+                "exchange_prefixed_mention_confidence",
+                self.exchange_prefixed_mention_confidence,
+            ),
+        ];
+
+        for (name, value) in ratios {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(Error::ConfigError(format!(
+                    "`{}` must be between 0.0 and 1.0, got {}",
+                    name, value
+                )));
+            }
+        }
+
+        self.stop_words.validate()
+    }
+
+    /// Determines whether the configuration includes the same entries of a company
+    /// symbol list as another configuration, and tokenizes their company names
+    /// with the same stop words and normalization, in which case both produce the
+    /// same symbol index.
+    ///
+    /// # Arguments
+    /// * `other` - A reference to the configuration to compare against.
+    pub(crate) fn has_same_token_maps(&self, other: &CompanyTokenProcessorConfig) -> bool {
+        self.included_asset_types == other.included_asset_types
+            && self.excluded_asset_types == other.excluded_asset_types
+            && self.included_markets == other.included_markets
+            && self.as_of_date == other.as_of_date
+            && self.stop_words.to_uppercase_set() == other.stop_words.to_uppercase_set()
+            && self.fold_diacritics == other.fold_diacritics
+    }
+
+    /// Determines whether ticker symbols of the given asset type should be matched.
+    ///
+    /// Ticker symbols with an unknown asset type are always matched.
+    ///
+    /// # Arguments
+    /// * `asset_type` - The asset type of a ticker symbol, if known.
+    pub fn is_asset_type_included(&self, asset_type: Option<AssetType>) -> bool {
+        let Some(asset_type) = asset_type else {
+            return true;
+        };
+
+        let is_included = self
+            .included_asset_types
+            .as_ref()
+            .is_none_or(|included_asset_types| included_asset_types.contains(&asset_type));
+
+        is_included && !self.excluded_asset_types.contains(&asset_type)
+    }
+
+    /// Determines whether ticker symbols listed on the given market should be matched.
+    ///
+    /// # Arguments
+    /// * `market` - The market a ticker symbol is listed on.
+    pub fn is_market_included(&self, market: Market) -> bool {
+        self.included_markets
+            .as_ref()
+            .is_none_or(|included_markets| included_markets.contains(&market))
+    }
+}
+
+impl Default for CompanyTokenProcessorConfig {
+    fn default() -> Self {
+        DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG.clone()
+    }
+}
+
+/// A builder for `CompanyTokenProcessorConfig`.
+///
+/// Any field which is not explicitly set retains its default value.
+#[derive(Debug, Clone, Default)]
+pub struct CompanyTokenProcessorConfigBuilder {
+    config: CompanyTokenProcessorConfig,
+}
+
+impl CompanyTokenProcessorConfigBuilder {
+    /// Sets the minimum ratio of exact ticker symbol matches required to keep bare
+    /// ticker symbols.
+    pub fn threshold_ratio_exact_matches(mut self, value: f32) -> Self {
+        self.config.threshold_ratio_exact_matches = value;
+        self
+    }
+
+    /// Sets the minimum company token coverage required for a company name match.
+    pub fn threshold_min_company_token_coverage(mut self, value: f32) -> Self {
+        self.config.threshold_min_company_token_coverage = value;
+        self
+    }
+
+    /// Sets the minimum ratio of uppercase letters required for a ticker symbol token.
+    pub fn min_ticker_symbol_uppercase_ratio(mut self, value: f32) -> Self {
+        self.config.min_ticker_symbol_uppercase_ratio = value;
+        self
+    }
+
+    /// Sets the confidence assigned to bare ticker symbol mentions.
+    pub fn ticker_symbol_mention_confidence(mut self, value: f32) -> Self {
+        self.config.ticker_symbol_mention_confidence = value;
+        self
+    }
+
+    /// Sets the confidence assigned to cashtag mentions.
+    pub fn cashtag_mention_confidence(mut self, value: f32) -> Self {
+        self.config.cashtag_mention_confidence = value;
+        self
+    }
+
+    /// Sets the confidence assigned to exchange-prefixed ticker symbol mentions.
+    pub fn exchange_prefixed_mention_confidence(mut self, value: f32) -> Self {
+        self.config.exchange_prefixed_mention_confidence = value;
+        self
+    }
+
+    /// Restricts matching to ticker symbols with one of the given asset types.
+    pub fn included_asset_types<I: IntoIterator<Item = AssetType>>(
+        mut self,
+        asset_types: I,
+    ) -> Self {
+        self.config.included_asset_types = Some(asset_types.into_iter().collect());
+        self
+    }
+
+    /// Prevents ticker symbols with any of the given asset types from being matched.
+    pub fn excluded_asset_types<I: IntoIterator<Item = AssetType>>(
+        mut self,
+        asset_types: I,
+    ) -> Self {
+        self.config.excluded_asset_types = asset_types.into_iter().collect();
+        self
+    }
+
+    /// Restricts matching to ticker symbols listed on one of the given markets.
+    pub fn included_markets<I: IntoIterator<Item = Market>>(mut self, markets: I) -> Self {
+        self.config.included_markets = Some(markets.into_iter().collect());
+        self
+    }
+
+    /// Sets the date text documents should be interpreted as of.
+    pub fn as_of_date(mut self, as_of_date: Date) -> Self {
+        self.config.as_of_date = Some(as_of_date);
+        self
+    }
+
+    /// Replaces the stop words.
+    pub fn stop_words(mut self, stop_words: StopWords) -> Self {
+        self.config.stop_words = stop_words;
+        self
+    }
+
+    /// Replaces the stop words with those of a preset.
+    pub fn stop_word_preset(mut self, preset: StopWordPreset) -> Self {
+        self.config.stop_words = StopWords::new(preset);
+        self
+    }
+
+    /// Sets whether diacritics are removed from letters.
+    pub fn fold_diacritics(mut self, fold_diacritics: bool) -> Self {
+        self.config.fold_diacritics = fold_diacritics;
+        self
+    }
+
+    /// Adds words to the stop words.
+    pub fn add_stop_words<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.config.stop_words = self.config.stop_words.with_added_words(words);
+        self
+    }
+
+    /// Removes words from the stop words.
+    pub fn remove_stop_words<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.config.stop_words = self.config.stop_words.with_removed_words(words);
+        self
+    }
+
+    /// Builds the configuration.
+    ///
+    /// # Errors
+    /// Returns `Error::ConfigError` if the configuration is invalid.
+    pub fn build(self) -> Result<CompanyTokenProcessorConfig, Error> {
+        self.config.validate()?;
+
+        Ok(self.config)
+    }
+}
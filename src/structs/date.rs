@@ -0,0 +1,122 @@
+use crate::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A calendar date, used to describe when ticker symbols and company names were valid.
+///
+/// Dates are formatted as `YYYY-MM-DD`, and are ordered chronologically.
+///
+/// # Example
+/// ```
+/// use ticker_sniffer::Date;
+///
+/// let date: Date = "2019-06-30".parse().unwrap();
+///
+/// assert_eq!(date, Date::new(2019, 6, 30).unwrap());
+/// assert_eq!(date.to_string(), "2019-06-30");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "String", into = "String")
+)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Creates a date.
+    ///
+    /// # Arguments
+    /// * `year` - The year (e.g., `2019`).
+    /// * `month` - The month, from `1` to `12`.
+    /// * `day` - The day of the month, starting from `1`.
+    ///
+    /// # Errors
+    /// Returns `Error::ParserError` if the month or day is out of range.
+    pub fn new(year: u16, month: u8, day: u8) -> Result<Self, Error> {
+        if !(1..=12).contains(&month) || day == 0 || day > Self::days_in_month(year, month) {
+            return Err(Error::ParserError(format!(
+                "Invalid date '{:04}-{:02}-{:02}'",
+                year, month, day
+            )));
+        }
+
+        Ok(Self { year, month, day })
+    }
+
+    /// Returns the year.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Returns the month, from `1` to `12`.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Determines the number of days in the given month.
+    fn days_in_month(year: u16, month: u8) -> u8 {
+        match month {
+            2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = Error;
+
+    /// Parses a date formatted as `YYYY-MM-DD`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let invalid_date =
+            || Error::ParserError(format!("Invalid date '{}', expected YYYY-MM-DD", s));
+
+        let mut parts = s.splitn(3, '-');
+        let (Some(year), Some(month), Some(day)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid_date());
+        };
+
+        if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+            return Err(invalid_date());
+        }
+
+        Self::new(
+            year.parse().map_err(|_| invalid_date())?,
+            month.parse().map_err(|_| invalid_date())?,
+            day.parse().map_err(|_| invalid_date())?,
+        )
+    }
+}
+
+impl TryFrom<String> for Date {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<Date> for String {
+    fn from(date: Date) -> Self {
+        date.to_string()
+    }
+}
@@ -1,10 +1,51 @@
+use crate::types::{TickerSymbol, TokenId};
 use std::fmt;
 
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
+    /// A general parsing failure.
     ParserError(String),
-    TokenFilterError(String),
+
+    /// The compressed company symbol list or symbol index could not be decompressed.
+    DecompressionError(std::io::Error),
+
+    /// A row of a CSV file could not be parsed.
+    CsvRowError {
+        /// The 1-based line number of the row, if known.
+        line: Option<u64>,
+
+        /// The name of the column which could not be parsed, if known.
+        column: Option<String>,
+
+        /// A description of the failure.
+        message: String,
+
+        /// The underlying cause, if any.
+        source: Option<Box<dyn std::error::Error + Send + Sync>>,
+    },
+
+    /// A required column header is missing from a CSV file.
+    MissingHeaderError(String),
+
+    /// A token ID does not correspond to any known ticker symbol.
+    UnknownTokenIdError(TokenId),
+
+    /// A ticker symbol is not present in the company symbol list.
+    UnknownTickerSymbolError(TickerSymbol),
+
+    /// A TOML file could not be parsed.
+    #[cfg(feature = "toml")]
+    TomlError(toml::de::Error),
+
+    /// A prebuilt symbol index could not be read.
+    SymbolIndexError(String),
+
+    /// The provided configuration is invalid.
+    ConfigError(String),
+
     IoError(std::io::Error),
+
     Other(String),
 }
 
@@ -12,13 +53,63 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Error::ParserError(msg) => write!(f, "Parser Error: {}", msg),
-            Error::TokenFilterError(msg) => write!(f, "Token Filter Error: {}", msg),
+            Error::DecompressionError(err) => write!(f, "Decompression Error: {}", err),
+            Error::CsvRowError {
+                line,
+                column,
+                message,
+                ..
+            } => {
+                write!(f, "CSV Row Error")?;
+
+                if let Some(line) = line {
+                    write!(f, " (line {}", line)?;
+
+                    if let Some(column) = column {
+                        write!(f, ", column '{}'", column)?;
+                    }
+
+                    write!(f, ")")?;
+                } else if let Some(column) = column {
+                    write!(f, " (column '{}')", column)?;
+                }
+
+                write!(f, ": {}", message)
+            }
+            Error::MissingHeaderError(header) => {
+                write!(f, "Missing Header Error: '{}'", header)
+            }
+            Error::UnknownTokenIdError(token_id) => {
+                write!(f, "Unknown Token ID Error: {}", token_id)
+            }
+            Error::UnknownTickerSymbolError(ticker_symbol) => {
+                write!(f, "Unknown Ticker Symbol Error: {}", ticker_symbol)
+            }
+            #[cfg(feature = "toml")]
+            Error::TomlError(err) => write!(f, "TOML Error: {}", err),
+            Error::SymbolIndexError(msg) => write!(f, "Symbol Index Error: {}", msg),
+            Error::ConfigError(msg) => write!(f, "Config Error: {}", msg),
             Error::IoError(err) => write!(f, "IO Error: {}", err),
             Error::Other(msg) => write!(f, "Other Error: {}", msg),
         }
     }
 }
 
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::DecompressionError(err) | Error::IoError(err) => Some(err),
+            #[cfg(feature = "toml")]
+            Error::TomlError(err) => Some(err),
+            Error::CsvRowError {
+                source: Some(source),
+                ..
+            } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
 impl From<String> for Error {
     fn from(msg: String) -> Error {
         Error::ParserError(msg)
@@ -37,6 +128,18 @@ impl From<std::io::Error> for Error {
     }
 }
 
+#[cfg(feature = "csv")]
+impl From<csv::Error> for Error {
+    fn from(err: csv::Error) -> Error {
+        Error::CsvRowError {
+            line: err.position().map(|position| position.line()),
+            column: None,
+            message: err.to_string(),
+            source: Some(Box::new(err)),
+        }
+    }
+}
+
 impl From<Box<dyn std::error::Error>> for Error {
     fn from(err: Box<dyn std::error::Error>) -> Error {
         Error::Other(err.to_string())
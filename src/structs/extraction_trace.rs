@@ -0,0 +1,113 @@
+use crate::types::{TickerSymbol, TickerSymbolFrequency, TickerSymbolFrequencyMap, TokenId};
+use crate::{TokenParityState, TokenRangeState, TokenSpan};
+
+/// Describes what happened to a bare ticker symbol found in a text document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TickerSymbolTraceOutcome {
+    /// The ticker symbol was kept as a standalone match.
+    Kept,
+
+    /// The ticker symbol was dropped because the ratio of exact ticker symbol
+    /// matches in the document was below `threshold_ratio_exact_matches`.
+    DroppedByExactMatchRatio,
+
+    /// The ticker symbol was not counted separately because the same ticker
+    /// symbol was already matched by company name.
+    MatchedByCompanyName,
+
+    /// The ticker symbol was not counted because it was part of a company name
+    /// match for a different ticker symbol (e.g., "DOW" in "Dow Jones").
+    AbsorbedByCompanyNameRange,
+}
+
+/// Records the decision made for a bare ticker symbol found in a text document.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TickerSymbolTrace {
+    /// The ticker symbol.
+    pub ticker_symbol: TickerSymbol,
+
+    /// Each occurrence of the ticker symbol in the text document.
+    pub token_spans: Vec<TokenSpan>,
+
+    /// The frequency contributed to the results by the bare ticker symbol.
+    pub frequency: TickerSymbolFrequency,
+
+    /// What happened to the ticker symbol.
+    pub outcome: TickerSymbolTraceOutcome,
+}
+
+/// A structured record of each stage of processing a text document.
+///
+/// This is intended for diagnosing missing or spurious ticker symbols, and is
+/// only collected when explicitly requested (e.g., via `TickerSniffer::explain`).
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExtractionTrace {
+    /// The text document tokens, before being filtered to known tokens.
+    pub text_doc_tokens: Vec<TokenSpan>,
+
+    /// The ticker symbol tokens, before being filtered to known ticker symbols.
+    pub ticker_symbol_tokens: Vec<TokenSpan>,
+
+    /// The cashtag tokens (e.g., "AAPL" for "$AAPL"), before being filtered to
+    /// known ticker symbols.
+    pub cashtag_tokens: Vec<TokenSpan>,
+
+    /// The exchange-prefixed ticker symbol tokens (e.g., "AAPL" for "NASDAQ: AAPL"),
+    /// before being filtered to known ticker symbols. Their spans include the
+    /// exchange name.
+    pub exchange_prefixed_tokens: Vec<TokenSpan>,
+
+    /// The filtered text document token IDs, indexed by query token index.
+    pub query_text_doc_token_ids: Vec<TokenId>,
+
+    /// The spans of the filtered text document tokens, indexed by query token index.
+    pub query_text_doc_token_spans: Vec<TokenSpan>,
+
+    /// The filtered ticker symbol token IDs, before the exact match ratio is applied.
+    pub query_ticker_symbol_token_ids: Vec<TokenId>,
+
+    /// The filtered cashtag token IDs. Text document and ticker symbol tokens at
+    /// the same positions are excluded from the other stages.
+    pub query_cashtag_token_ids: Vec<TokenId>,
+
+    /// The filtered exchange-prefixed ticker symbol token IDs. Any other tokens
+    /// overlapping their spans are excluded from the other stages.
+    pub query_exchange_prefixed_token_ids: Vec<TokenId>,
+
+    /// The token parity states between the query and company token sequences.
+    pub token_parity_states: Vec<TokenParityState>,
+
+    /// The scored token range states which met the minimum company token coverage.
+    pub token_range_states: Vec<TokenRangeState>,
+
+    /// The scored token range states which fell below `threshold_min_company_token_coverage`.
+    pub discarded_token_range_states: Vec<TokenRangeState>,
+
+    /// The top scoring token range states.
+    pub top_range_states: Vec<TokenRangeState>,
+
+    /// The ratio of exact ticker symbol matches among the top range states.
+    pub ratio_exact_matches: f32,
+
+    /// The configured minimum ratio of exact matches required to keep bare ticker symbols.
+    pub threshold_ratio_exact_matches: f32,
+
+    /// The configured minimum company token coverage required to keep a token range state.
+    pub threshold_min_company_token_coverage: f32,
+
+    /// The decisions made for each bare ticker symbol found in the text document.
+    pub ticker_symbol_traces: Vec<TickerSymbolTrace>,
+
+    /// The decisions made for each cashtag found in the text document.
+    pub cashtag_traces: Vec<TickerSymbolTrace>,
+
+    /// The decisions made for each exchange-prefixed ticker symbol found in the
+    /// text document.
+    pub exchange_prefixed_traces: Vec<TickerSymbolTrace>,
+
+    /// The final ticker symbol frequencies.
+    pub ticker_symbol_frequency_map: TickerSymbolFrequencyMap,
+}
@@ -0,0 +1,198 @@
+use crate::types::TickerSymbol;
+use crate::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// The market a ticker symbol is listed on.
+///
+/// Ticker symbols listed outside of the U.S. are qualified with the market's
+/// suffix, following the convention `<SYMBOL>.<SUFFIX>` (e.g., "VOD.L", "SHOP.TO",
+/// or "7203.T").
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Market {
+    /// U.S. exchanges (e.g., NYSE and NASDAQ). Ticker symbols are not qualified.
+    #[default]
+    Us,
+
+    /// The London Stock Exchange.
+    London,
+
+    /// The Toronto Stock Exchange.
+    Toronto,
+
+    /// The TSX Venture Exchange.
+    TorontoVenture,
+
+    /// The Tokyo Stock Exchange.
+    Tokyo,
+
+    /// The Hong Kong Stock Exchange.
+    HongKong,
+
+    /// The Australian Securities Exchange.
+    Australia,
+}
+
+impl Market {
+    /// All markets, in declaration order.
+    pub const ALL: [Market; 7] = [
+        Market::Us,
+        Market::London,
+        Market::Toronto,
+        Market::TorontoVenture,
+        Market::Tokyo,
+        Market::HongKong,
+        Market::Australia,
+    ];
+
+    /// Returns the label used for the market in the company symbol list.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Market::Us => "US",
+            Market::London => "London",
+            Market::Toronto => "Toronto",
+            Market::TorontoVenture => "Toronto Venture",
+            Market::Tokyo => "Tokyo",
+            Market::HongKong => "Hong Kong",
+            Market::Australia => "Australia",
+        }
+    }
+
+    /// Returns the suffix which qualifies ticker symbols listed on the market,
+    /// or `None` if ticker symbols are not qualified.
+    pub fn suffix(&self) -> Option<&'static str> {
+        match self {
+            Market::Us => None,
+            Market::London => Some("L"),
+            Market::Toronto => Some("TO"),
+            Market::TorontoVenture => Some("V"),
+            Market::Tokyo => Some("T"),
+            Market::HongKong => Some("HK"),
+            Market::Australia => Some("AX"),
+        }
+    }
+
+    /// Returns the exchange names which identify the market when prefixed to a
+    /// ticker symbol in text (e.g., "NASDAQ" in "(NASDAQ: AAPL)" or "TSX" in
+    /// "TSX: SHOP"), in uppercase.
+    pub fn exchange_prefixes(&self) -> &'static [&'static str] {
+        match self {
+            Market::Us => &[
+                "NASDAQ",
+                "NASDAQGS",
+                "NASDAQGM",
+                "NASDAQCM",
+                "NASDAQ GS",
+                "NASDAQ GM",
+                "NASDAQ CM",
+                "NASDAQ GLOBAL SELECT",
+                "NASDAQ GLOBAL MARKET",
+                "NASDAQ CAPITAL MARKET",
+                "NYSE",
+                "NYSE AMERICAN",
+                "NYSE ARCA",
+                "NYSE MKT",
+                "NYSEAMERICAN",
+                "NYSEARCA",
+                "NYSEMKT",
+                "AMEX",
+                "CBOE",
+                "BATS",
+                "OTC",
+                "OTCQX",
+                "OTCQB",
+                "OTC PINK",
+                "OTC MARKETS",
+            ],
+            Market::London => &["LSE", "LON", "AIM"],
+            Market::Toronto => &["TSX"],
+            Market::TorontoVenture => &["TSXV", "TSX-V", "TSX VENTURE"],
+            Market::Tokyo => &["TSE", "TYO"],
+            Market::HongKong => &["HKEX", "SEHK", "HKG"],
+            Market::Australia => &["ASX"],
+        }
+    }
+
+    /// Finds the market identified by an exchange name prefixed to a ticker symbol
+    /// (e.g., "NYSE American" in "(NYSE American: XYZ)").
+    ///
+    /// # Arguments
+    /// * `exchange_prefix` - The exchange name, without the colon. The lookup is
+    ///   case insensitive, and repeated whitespace is ignored.
+    pub fn from_exchange_prefix(exchange_prefix: &str) -> Option<Market> {
+        let exchange_prefix = exchange_prefix
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ")
+            .to_uppercase();
+
+        Market::ALL.into_iter().find(|market| {
+            market
+                .exchange_prefixes()
+                .contains(&exchange_prefix.as_str())
+        })
+    }
+
+    /// Finds the market identified by a ticker symbol suffix.
+    ///
+    /// # Arguments
+    /// * `suffix` - The suffix, without the leading period. The lookup is case insensitive.
+    pub fn from_suffix(suffix: &str) -> Option<Market> {
+        Market::ALL.into_iter().find(|market| {
+            market
+                .suffix()
+                .is_some_and(|market_suffix| market_suffix.eq_ignore_ascii_case(suffix))
+        })
+    }
+
+    /// Determines the market of a ticker symbol from its suffix, defaulting to
+    /// `Market::Us` if the ticker symbol is not qualified.
+    ///
+    /// # Arguments
+    /// * `ticker_symbol` - The ticker symbol (e.g., "VOD.L").
+    pub fn from_ticker_symbol(ticker_symbol: &str) -> Market {
+        ticker_symbol
+            .rsplit_once('.')
+            .filter(|(base, _)| !base.is_empty())
+            .and_then(|(_, suffix)| Market::from_suffix(suffix))
+            .unwrap_or_default()
+    }
+
+    /// Qualifies a ticker symbol with the market's suffix, if it is not already.
+    ///
+    /// # Arguments
+    /// * `ticker_symbol` - The ticker symbol (e.g., "VOD" or "VOD.L").
+    ///
+    /// # Returns
+    /// * The qualified ticker symbol (e.g., "VOD.L").
+    pub fn qualify_ticker_symbol(&self, ticker_symbol: &str) -> TickerSymbol {
+        match self.suffix() {
+            Some(suffix) if Market::from_ticker_symbol(ticker_symbol) != *self => {
+                format!("{}.{}", ticker_symbol, suffix)
+            }
+            _ => ticker_symbol.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Market {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Market {
+    type Err = Error;
+
+    /// Parses a market from its label or its suffix, ignoring case (e.g., "London" or "L").
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        Market::ALL
+            .into_iter()
+            .find(|market| market.as_str().eq_ignore_ascii_case(s))
+            .or_else(|| Market::from_suffix(s))
+            .ok_or_else(|| Error::ParserError(format!("Unknown market '{}'", s)))
+    }
+}
@@ -0,0 +1,837 @@
+use crate::{Error, TickerSymbolMention};
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+/// HTML elements whose contents are never rendered as text.
+const HTML_HIDDEN_ELEMENTS: &[&str] = &["script", "style", "noscript", "template"];
+
+/// HTML elements which start a new block of text, so that the words on either
+/// side of them are not joined together.
+const HTML_BLOCK_ELEMENTS: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "caption",
+    "dd",
+    "details",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "li",
+    "main",
+    "nav",
+    "ol",
+    "option",
+    "p",
+    "pre",
+    "section",
+    "summary",
+    "table",
+    "tbody",
+    "td",
+    "tfoot",
+    "th",
+    "thead",
+    "title",
+    "tr",
+    "ul",
+];
+
+/// The named character references which are decoded, as `(name, character)`.
+///
+/// Numeric character references (e.g., "&#38;" or "&#x26;") are always decoded.
+const HTML_NAMED_ENTITIES: &[(&str, char)] = &[
+    ("amp", '&'),
+    ("lt", '<'),
+    ("gt", '>'),
+    ("quot", '"'),
+    ("apos", '\''),
+    ("nbsp", '\u{A0}'),
+    ("shy", '\u{AD}'),
+    ("ndash", '\u{2013}'),
+    ("mdash", '\u{2014}'),
+    ("lsquo", '\u{2018}'),
+    ("rsquo", '\u{2019}'),
+    ("ldquo", '\u{201C}'),
+    ("rdquo", '\u{201D}'),
+    ("hellip", '\u{2026}'),
+    ("middot", '\u{B7}'),
+    ("bull", '\u{2022}'),
+    ("copy", '\u{A9}'),
+    ("reg", '\u{AE}'),
+    ("trade", '\u{2122}'),
+    ("cent", '\u{A2}'),
+    ("pound", '\u{A3}'),
+    ("yen", '\u{A5}'),
+    ("euro", '\u{20AC}'),
+    ("Aacute", '\u{C1}'),
+    ("aacute", '\u{E1}'),
+    ("Agrave", '\u{C0}'),
+    ("agrave", '\u{E0}'),
+    ("Auml", '\u{C4}'),
+    ("auml", '\u{E4}'),
+    ("Ccedil", '\u{C7}'),
+    ("ccedil", '\u{E7}'),
+    ("Eacute", '\u{C9}'),
+    ("eacute", '\u{E9}'),
+    ("Egrave", '\u{C8}'),
+    ("egrave", '\u{E8}'),
+    ("Iacute", '\u{CD}'),
+    ("iacute", '\u{ED}'),
+    ("Ntilde", '\u{D1}'),
+    ("ntilde", '\u{F1}'),
+    ("Oacute", '\u{D3}'),
+    ("oacute", '\u{F3}'),
+    ("Ouml", '\u{D6}'),
+    ("ouml", '\u{F6}'),
+    ("Uacute", '\u{DA}'),
+    ("uacute", '\u{FA}'),
+    ("Uuml", '\u{DC}'),
+    ("uuml", '\u{FC}'),
+    ("szlig", '\u{DF}'),
+];
+
+/// The maximum length of a character reference name (e.g., "x1F4C8").
+const MAX_HTML_ENTITY_NAME_LEN: usize = 32;
+
+/// The markup language of a source document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MarkupFormat {
+    /// Plain text, which is used as-is.
+    #[default]
+    PlainText,
+
+    /// HTML, whose tags, comments, scripts, and styles are removed, and whose
+    /// character references (e.g., "&amp;") are decoded.
+    Html,
+
+    /// Markdown, whose formatting syntax (e.g., emphasis, headings, and link
+    /// destinations) and fenced code blocks are removed.
+    Markdown,
+}
+
+impl MarkupFormat {
+    /// All formats, in declaration order.
+    pub const ALL: [MarkupFormat; 3] = [
+        MarkupFormat::PlainText,
+        MarkupFormat::Html,
+        MarkupFormat::Markdown,
+    ];
+
+    /// Returns a stable identifier for the format (e.g., "html").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MarkupFormat::PlainText => "text",
+            MarkupFormat::Html => "html",
+            MarkupFormat::Markdown => "markdown",
+        }
+    }
+}
+
+impl fmt::Display for MarkupFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for MarkupFormat {
+    type Err = Error;
+
+    /// Parses a format from its identifier, ignoring case (e.g., "HTML").
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        MarkupFormat::ALL
+            .into_iter()
+            .find(|format| format.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| Error::ParserError(format!("Unknown markup format '{}'", s)))
+    }
+}
+
+/// A character of the visible text of a `MarkupText`, and the part of the
+/// source it was extracted from.
+#[derive(Debug, Clone)]
+struct SourceSpan {
+    /// The byte offset of the character in the visible text.
+    text_byte_offset: usize,
+
+    /// The byte range of the character in the source (e.g., all of "&amp;").
+    byte_range: Range<usize>,
+
+    /// The character range of the character in the source.
+    char_range: Range<usize>,
+}
+
+/// The visible text of an HTML or Markdown document, which can be processed
+/// like any other text document, along with the mapping of each of its
+/// characters back to the source.
+///
+/// Block boundaries (e.g., paragraphs, list items, and table cells) are
+/// separated by line breaks, so that words on either side of them are never
+/// joined together. Line breaks which are inserted this way map to an empty
+/// range of the source.
+///
+/// # Example
+/// ```
+/// use ticker_sniffer::{MarkupFormat, MarkupText};
+///
+/// let html = "<p>Shares of <b>AT&amp;T</b> rose.</p><script>var x = 1;</script>";
+/// let markup_text = MarkupText::new(html, MarkupFormat::Html);
+///
+/// assert_eq!(markup_text.text(), "Shares of AT&T rose.");
+///
+/// let byte_range = markup_text.to_source_byte_range(&(10..14));
+/// assert_eq!(&html[byte_range], "AT&amp;T");
+/// ```
+#[derive(Debug, Clone)]
+pub struct MarkupText<'a> {
+    source: &'a str,
+    text: String,
+    source_spans: Vec<SourceSpan>,
+}
+
+impl<'a> MarkupText<'a> {
+    /// Extracts the visible text of a source document.
+    ///
+    /// # Arguments
+    /// * `source` - The source document.
+    /// * `format` - The markup language of the source document.
+    pub fn new(source: &'a str, format: MarkupFormat) -> Self {
+        let mut builder = MarkupTextBuilder::new(source);
+
+        match format {
+            MarkupFormat::PlainText => builder.push_chars(0..builder.chars.len()),
+            MarkupFormat::Html => builder.push_html(),
+            MarkupFormat::Markdown => builder.push_markdown(),
+        }
+
+        // Drop the line breaks which end the last block
+        while builder
+            .source_spans
+            .last()
+            .is_some_and(|span| span.byte_range.is_empty())
+        {
+            builder.text.pop();
+            builder.source_spans.pop();
+        }
+
+        Self {
+            source,
+            text: builder.text,
+            source_spans: builder.source_spans,
+        }
+    }
+
+    /// Extracts the visible text of an HTML document.
+    ///
+    /// # Arguments
+    /// * `html` - The HTML document.
+    pub fn from_html(html: &'a str) -> Self {
+        Self::new(html, MarkupFormat::Html)
+    }
+
+    /// Extracts the visible text of a Markdown document.
+    ///
+    /// # Arguments
+    /// * `markdown` - The Markdown document.
+    pub fn from_markdown(markdown: &'a str) -> Self {
+        Self::new(markdown, MarkupFormat::Markdown)
+    }
+
+    /// Returns the source document.
+    pub fn source(&self) -> &'a str {
+        self.source
+    }
+
+    /// Returns the visible text of the source document.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Maps a byte range of the visible text to the byte range of the source it
+    /// was extracted from.
+    ///
+    /// # Arguments
+    /// * `text_byte_range` - A byte range of the visible text.
+    ///
+    /// # Returns
+    /// * The byte range of the source, from the start of the first character of
+    ///   the range to the end of its last character. An empty range maps to an
+    ///   empty range of the source.
+    pub fn to_source_byte_range(&self, text_byte_range: &Range<usize>) -> Range<usize> {
+        match self.find_source_spans(text_byte_range) {
+            Ok((first, last)) => first.byte_range.start..last.byte_range.end,
+            Err(Some(next)) => next.byte_range.start..next.byte_range.start,
+            Err(None) => self.source.len()..self.source.len(),
+        }
+    }
+
+    /// Maps a byte range of the visible text to the character range of the
+    /// source it was extracted from.
+    ///
+    /// # Arguments
+    /// * `text_byte_range` - A byte range of the visible text.
+    pub fn to_source_char_range(&self, text_byte_range: &Range<usize>) -> Range<usize> {
+        match self.find_source_spans(text_byte_range) {
+            Ok((first, last)) => first.char_range.start..last.char_range.end,
+            Err(Some(next)) => next.char_range.start..next.char_range.start,
+            Err(None) => {
+                let char_len = self.source.chars().count();
+                char_len..char_len
+            }
+        }
+    }
+
+    /// Maps a mention extracted from the visible text to the source.
+    ///
+    /// The byte and character ranges of the mention are mapped to the source,
+    /// while its text is kept as it appears in the visible text (e.g., "AT&T"
+    /// rather than "AT&amp;T").
+    ///
+    /// # Arguments
+    /// * `mention` - A mention extracted from the visible text.
+    pub fn to_source_mention(&self, mention: TickerSymbolMention) -> TickerSymbolMention {
+        TickerSymbolMention {
+            byte_range: self.to_source_byte_range(&mention.byte_range),
+            char_range: self.to_source_char_range(&mention.byte_range),
+            ..mention
+        }
+    }
+
+    /// Finds the source spans of the first and last characters of a byte range
+    /// of the visible text, or, if the range contains no characters, the source
+    /// span of the next character, if any.
+    fn find_source_spans(
+        &self,
+        text_byte_range: &Range<usize>,
+    ) -> Result<(&SourceSpan, &SourceSpan), Option<&SourceSpan>> {
+        let first_idx = self
+            .source_spans
+            .partition_point(|span| span.text_byte_offset < text_byte_range.start);
+        let end_idx = self
+            .source_spans
+            .partition_point(|span| span.text_byte_offset < text_byte_range.end);
+
+        if first_idx < end_idx {
+            Ok((
+                &self.source_spans[first_idx],
+                &self.source_spans[end_idx - 1],
+            ))
+        } else {
+            Err(self.source_spans.get(first_idx))
+        }
+    }
+}
+
+/// Builds the visible text of a `MarkupText`, one character at a time.
+struct MarkupTextBuilder<'a> {
+    source: &'a str,
+    chars: Vec<(usize, char)>,
+    text: String,
+    source_spans: Vec<SourceSpan>,
+}
+
+impl<'a> MarkupTextBuilder<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            chars: source.char_indices().collect(),
+            text: String::with_capacity(source.len()),
+            source_spans: Vec::new(),
+        }
+    }
+
+    /// Returns the byte offset of the source character at `char_idx`.
+    fn byte_offset(&self, char_idx: usize) -> usize {
+        self.chars
+            .get(char_idx)
+            .map_or(self.source.len(), |(byte_offset, _)| *byte_offset)
+    }
+
+    /// Returns the source character at `char_idx`, if any.
+    fn char_at(&self, char_idx: usize) -> Option<char> {
+        self.chars.get(char_idx).map(|(_, c)| *c)
+    }
+
+    /// Determines whether the source continues with `pattern` at `char_idx`.
+    fn starts_with(&self, char_idx: usize, pattern: &str, ignore_case: bool) -> bool {
+        let rest = &self.source[self.byte_offset(char_idx)..];
+
+        rest.get(..pattern.len()).is_some_and(|prefix| {
+            if ignore_case {
+                prefix.eq_ignore_ascii_case(pattern)
+            } else {
+                prefix == pattern
+            }
+        })
+    }
+
+    /// Finds the first occurrence of `pattern` at or after `char_idx`, within
+    /// `end`, returning the character index it starts at.
+    fn find(&self, char_idx: usize, end: usize, pattern: &str) -> Option<usize> {
+        (char_idx..end).find(|idx| self.starts_with(*idx, pattern, false))
+    }
+
+    /// Appends a character of the visible text, extracted from a character
+    /// range of the source.
+    fn push(&mut self, c: char, char_range: Range<usize>) {
+        self.source_spans.push(SourceSpan {
+            text_byte_offset: self.text.len(),
+            byte_range: self.byte_offset(char_range.start)..self.byte_offset(char_range.end),
+            char_range,
+        });
+        self.text.push(c);
+    }
+
+    /// Appends a character range of the source verbatim.
+    fn push_chars(&mut self, char_range: Range<usize>) {
+        for char_idx in char_range {
+            self.push(self.chars[char_idx].1, char_idx..char_idx + 1);
+        }
+    }
+
+    /// Ends the current block of text at `char_idx`, so that it is followed by
+    /// at least `line_break_count` line breaks. Breaks are never added before
+    /// the first character.
+    fn push_break(&mut self, char_idx: usize, line_break_count: usize) {
+        if self.text.is_empty() {
+            return;
+        }
+
+        let trailing_count = self.text.chars().rev().take_while(|c| *c == '\n').count();
+        for _ in trailing_count..line_break_count {
+            self.push('\n', char_idx..char_idx);
+        }
+    }
+
+    /// Appends a character reference (e.g., "&amp;") starting at `char_idx`,
+    /// returning the character index after it, or `None` if there is no valid
+    /// character reference.
+    fn push_entity(&mut self, char_idx: usize) -> Option<usize> {
+        let name_start = char_idx + 1;
+        let name_end = (name_start..self.chars.len())
+            .take(MAX_HTML_ENTITY_NAME_LEN + 1)
+            .find(|idx| !self.chars[*idx].1.is_ascii_alphanumeric() && self.chars[*idx].1 != '#')?;
+
+        if self.char_at(name_end) != Some(';') {
+            return None;
+        }
+
+        let name = &self.source[self.byte_offset(name_start)..self.byte_offset(name_end)];
+        let decoded =
+            if let Some(hex) = name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                u32::from_str_radix(hex, 16).ok().and_then(char::from_u32)
+            } else if let Some(decimal) = name.strip_prefix('#') {
+                decimal.parse().ok().and_then(char::from_u32)
+            } else {
+                HTML_NAMED_ENTITIES
+                    .iter()
+                    .find(|(entity_name, _)| *entity_name == name)
+                    .map(|(_, c)| *c)
+            }
+            .filter(|c| *c != '\0')?;
+
+        self.push(decoded, char_idx..name_end + 1);
+
+        Some(name_end + 1)
+    }
+
+    /// Parses an HTML tag starting at `char_idx`, returning its lowercase name,
+    /// whether it is a closing tag, and the character index after it.
+    fn parse_html_tag(&self, char_idx: usize) -> Option<(String, bool, usize)> {
+        let is_closing = self.char_at(char_idx + 1) == Some('/');
+        let name_start = char_idx + 1 + usize::from(is_closing);
+
+        if !self.char_at(name_start)?.is_ascii_alphabetic() {
+            return None;
+        }
+
+        let name_end = (name_start..self.chars.len())
+            .find(|idx| {
+                let c = self.chars[*idx].1;
+                !(c.is_ascii_alphanumeric() || c == '-' || c == ':')
+            })
+            .unwrap_or(self.chars.len());
+
+        // Find the end of the tag, skipping over quoted attribute values
+        let mut quote = None;
+        let tag_end = (name_end..self.chars.len()).find(|idx| {
+            let c = self.chars[*idx].1;
+            match quote {
+                Some(q) if c == q => quote = None,
+                Some(_) => {}
+                None if c == '"' || c == '\'' => quote = Some(c),
+                None => return c == '>',
+            }
+            false
+        })?;
+
+        let name = self.source[self.byte_offset(name_start)..self.byte_offset(name_end)]
+            .to_ascii_lowercase();
+
+        Some((name, is_closing, tag_end + 1))
+    }
+
+    /// Appends the visible text of the source, parsed as HTML.
+    fn push_html(&mut self) {
+        let len = self.chars.len();
+        let mut char_idx = 0;
+
+        while char_idx < len {
+            match self.chars[char_idx].1 {
+                '<' if self.starts_with(char_idx, "<!--", false) => {
+                    char_idx = self
+                        .find(char_idx + 4, len, "-->")
+                        .map_or(len, |end| end + 3);
+                }
+                '<' if matches!(self.char_at(char_idx + 1), Some('!' | '?')) => {
+                    char_idx = self.find(char_idx, len, ">").map_or(len, |end| end + 1);
+                }
+                '<' => {
+                    let Some((name, is_closing, tag_end)) = self.parse_html_tag(char_idx) else {
+                        self.push_chars(char_idx..char_idx + 1);
+                        char_idx += 1;
+                        continue;
+                    };
+
+                    if name == "br" {
+                        self.push_break(char_idx, 1);
+                    } else if HTML_BLOCK_ELEMENTS.contains(&name.as_str()) {
+                        self.push_break(char_idx, 2);
+                    }
+
+                    char_idx = tag_end;
+
+                    // Skip the contents of elements which are never rendered
+                    if !is_closing && HTML_HIDDEN_ELEMENTS.contains(&name.as_str()) {
+                        let closing_tag = format!("</{}", name);
+                        char_idx = (char_idx..len)
+                            .find(|idx| {
+                                self.starts_with(*idx, &closing_tag, true)
+                                    && self
+                                        .char_at(idx + closing_tag.len())
+                                        .is_none_or(|c| !c.is_ascii_alphanumeric())
+                            })
+                            .and_then(|closing_idx| self.parse_html_tag(closing_idx))
+                            .map_or(len, |(_, _, closing_end)| closing_end);
+                    }
+                }
+                '&' => {
+                    char_idx = self.push_entity(char_idx).unwrap_or_else(|| {
+                        self.push_chars(char_idx..char_idx + 1);
+                        char_idx + 1
+                    });
+                }
+                _ => {
+                    self.push_chars(char_idx..char_idx + 1);
+                    char_idx += 1;
+                }
+            }
+        }
+    }
+
+    /// Appends the visible text of the source, parsed as Markdown.
+    fn push_markdown(&mut self) {
+        let len = self.chars.len();
+        let mut open_fence: Option<(char, usize)> = None;
+        let mut line_start = 0;
+
+        while line_start < len {
+            let line_end = (line_start..len)
+                .find(|idx| self.chars[*idx].1 == '\n')
+                .unwrap_or(len);
+            let next_line_start = line_end + 1;
+
+            let content_start = (line_start..line_end)
+                .find(|idx| !self.chars[*idx].1.is_whitespace())
+                .unwrap_or(line_end);
+            let content_end = (content_start..line_end)
+                .rev()
+                .find(|idx| !self.chars[*idx].1.is_whitespace())
+                .map_or(content_start, |idx| idx + 1);
+            let content: String = self.chars[content_start..content_end]
+                .iter()
+                .map(|(_, c)| *c)
+                .collect();
+
+            let fence = ['`', '~'].into_iter().find_map(|fence_char| {
+                let fence_len = content.chars().take_while(|c| *c == fence_char).count();
+                (fence_len >= 3).then_some((fence_char, fence_len))
+            });
+
+            // Fenced code blocks are skipped, including their fences
+            if let Some((fence_char, fence_len)) = open_fence {
+                if fence.is_some_and(|(c, len)| c == fence_char && len >= fence_len)
+                    && content.trim_start_matches(fence_char).is_empty()
+                {
+                    open_fence = None;
+                }
+            } else if fence.is_some() {
+                open_fence = fence;
+                self.push_break(line_start, 2);
+            } else if content.is_empty() || Self::is_markdown_thematic_break(&content) {
+                self.push_break(line_start, 2);
+            } else if Self::is_markdown_table_delimiter(&content)
+                || Self::is_markdown_link_reference_definition(&content)
+            {
+                // Not visible
+            } else {
+                self.push_markdown_line(content_start, content_end);
+            }
+
+            line_start = next_line_start;
+        }
+    }
+
+    /// Appends the visible text of a non-blank line of Markdown, without its
+    /// block quote, list item, and heading markers.
+    fn push_markdown_line(&mut self, content_start: usize, content_end: usize) {
+        let mut inline_start = content_start;
+        let mut is_block_start = false;
+
+        loop {
+            let skip_spaces = |builder: &Self, idx: usize| {
+                (idx..content_end)
+                    .find(|idx| !builder.chars[*idx].1.is_whitespace())
+                    .unwrap_or(content_end)
+            };
+            let is_followed_by_space = |builder: &Self, idx: usize| {
+                idx >= content_end || builder.chars[idx].1.is_whitespace()
+            };
+
+            let c = self
+                .char_at(inline_start)
+                .filter(|_| inline_start < content_end);
+            let marker_end = match c {
+                Some('>') => Some(inline_start + 1),
+                Some('-' | '*' | '+') if is_followed_by_space(self, inline_start + 1) => {
+                    Some(inline_start + 1)
+                }
+                Some('#') => {
+                    let level = (inline_start..content_end)
+                        .take_while(|idx| self.chars[*idx].1 == '#')
+                        .count();
+                    (level <= 6 && is_followed_by_space(self, inline_start + level))
+                        .then_some(inline_start + level)
+                }
+                Some(c) if c.is_ascii_digit() => {
+                    let digit_count = (inline_start..content_end)
+                        .take_while(|idx| self.chars[*idx].1.is_ascii_digit())
+                        .count();
+                    let delimiter_idx = inline_start + digit_count;
+                    (digit_count <= 9
+                        && matches!(self.char_at(delimiter_idx), Some('.' | ')'))
+                        && is_followed_by_space(self, delimiter_idx + 1))
+                    .then_some(delimiter_idx + 1)
+                }
+                Some('[') if is_block_start => {
+                    // Task list item checkboxes (e.g., "[x]")
+                    (matches!(self.char_at(inline_start + 1), Some(' ' | 'x' | 'X'))
+                        && self.char_at(inline_start + 2) == Some(']')
+                        && is_followed_by_space(self, inline_start + 3))
+                    .then_some(inline_start + 3)
+                }
+                _ => None,
+            };
+
+            let Some(marker_end) = marker_end else {
+                break;
+            };
+
+            is_block_start = true;
+            inline_start = skip_spaces(self, marker_end);
+        }
+
+        if is_block_start {
+            self.push_break(content_start, 2);
+        }
+
+        self.push_markdown_inline(inline_start, content_end);
+
+        self.push_break(content_end, if is_block_start { 2 } else { 1 });
+    }
+
+    /// Appends the visible text of inline Markdown within a character range,
+    /// without its emphasis markers, link destinations, images, and HTML tags.
+    fn push_markdown_inline(&mut self, start: usize, end: usize) {
+        let mut char_idx = start;
+
+        while char_idx < end {
+            let c = self.chars[char_idx].1;
+            let next_char = self.char_at(char_idx + 1).filter(|_| char_idx + 1 < end);
+
+            match c {
+                '\\' if next_char.is_some_and(|c| c.is_ascii_punctuation()) => {
+                    self.push_chars(char_idx + 1..char_idx + 2);
+                    char_idx += 2;
+                }
+                '`' => {
+                    let tick_count = (char_idx..end)
+                        .take_while(|idx| self.chars[*idx].1 == '`')
+                        .count();
+                    let code_start = char_idx + tick_count;
+                    let ticks = "`".repeat(tick_count);
+
+                    match self.find(code_start, end, &ticks) {
+                        Some(code_end) => {
+                            self.push_chars(code_start..code_end);
+                            char_idx = code_end + tick_count;
+                        }
+                        None => {
+                            self.push_chars(char_idx..code_start);
+                            char_idx = code_start;
+                        }
+                    }
+                }
+                '!' if next_char == Some('[') => {
+                    match self.find_markdown_link_end(char_idx + 1, end) {
+                        Some((_, link_end)) => char_idx = link_end,
+                        None => {
+                            self.push_chars(char_idx..char_idx + 1);
+                            char_idx += 1;
+                        }
+                    }
+                }
+                '[' => match self.find_markdown_link_end(char_idx, end) {
+                    Some((label_end, link_end)) => {
+                        self.push_markdown_inline(char_idx + 1, label_end);
+                        char_idx = link_end;
+                    }
+                    None => {
+                        self.push_chars(char_idx..char_idx + 1);
+                        char_idx += 1;
+                    }
+                },
+                '<' if next_char
+                    .is_some_and(|c| c.is_ascii_alphabetic() || c == '/' || c == '!') =>
+                {
+                    match self.find(char_idx, end, ">") {
+                        Some(tag_end) => char_idx = tag_end + 1,
+                        None => {
+                            self.push_chars(char_idx..char_idx + 1);
+                            char_idx += 1;
+                        }
+                    }
+                }
+                '*' => char_idx += 1,
+                '~' if next_char == Some('~') => char_idx += 2,
+                '_' => {
+                    // Underscores within words (e.g., "snake_case") are not emphasis
+                    let previous_char = (char_idx > start).then(|| self.chars[char_idx - 1].1);
+                    let is_intraword = previous_char.is_some_and(char::is_alphanumeric)
+                        && next_char.is_some_and(char::is_alphanumeric);
+
+                    if is_intraword {
+                        self.push_chars(char_idx..char_idx + 1);
+                    }
+                    char_idx += 1;
+                }
+                '|' => {
+                    // Table cells are separated like words
+                    self.push(' ', char_idx..char_idx + 1);
+                    char_idx += 1;
+                }
+                '&' => {
+                    char_idx = self.push_entity(char_idx).unwrap_or_else(|| {
+                        self.push_chars(char_idx..char_idx + 1);
+                        char_idx + 1
+                    });
+                }
+                _ => {
+                    self.push_chars(char_idx..char_idx + 1);
+                    char_idx += 1;
+                }
+            }
+        }
+    }
+
+    /// Finds the end of a Markdown link (e.g., "[text](url)" or "[text][ref]")
+    /// whose label starts at `char_idx`, returning the character index of the
+    /// closing bracket of the label, and the character index after the link.
+    fn find_markdown_link_end(&self, char_idx: usize, end: usize) -> Option<(usize, usize)> {
+        let label_end = self.find_closing_bracket(char_idx, end, '[', ']')?;
+
+        match self.char_at(label_end + 1).filter(|_| label_end + 1 < end) {
+            Some('(') => {
+                let destination_end = self.find_closing_bracket(label_end + 1, end, '(', ')')?;
+                Some((label_end, destination_end + 1))
+            }
+            Some('[') => {
+                let reference_end = self.find_closing_bracket(label_end + 1, end, '[', ']')?;
+                Some((label_end, reference_end + 1))
+            }
+            _ => None,
+        }
+    }
+
+    /// Finds the bracket closing the one at `char_idx`, skipping over nested
+    /// and escaped brackets.
+    fn find_closing_bracket(
+        &self,
+        char_idx: usize,
+        end: usize,
+        open: char,
+        close: char,
+    ) -> Option<usize> {
+        let mut depth = 0;
+        let mut idx = char_idx;
+
+        while idx < end {
+            match self.chars[idx].1 {
+                '\\' => idx += 1,
+                c if c == open => depth += 1,
+                c if c == close => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(idx);
+                    }
+                }
+                _ => {}
+            }
+            idx += 1;
+        }
+
+        None
+    }
+
+    /// Determines whether a line of Markdown is a thematic break (e.g., "---"),
+    /// or a setext heading underline (e.g., "===").
+    fn is_markdown_thematic_break(content: &str) -> bool {
+        ['-', '*', '_', '='].into_iter().any(|marker| {
+            content.chars().filter(|c| *c == marker).count() >= 3
+                && content.chars().all(|c| c == marker || c == ' ')
+        })
+    }
+
+    /// Determines whether a line of Markdown is the delimiter row of a table
+    /// (e.g., "| --- | :---: |").
+    fn is_markdown_table_delimiter(content: &str) -> bool {
+        content.contains('-')
+            && content.contains('|')
+            && content
+                .chars()
+                .all(|c| matches!(c, '|' | '-' | ':' | ' ' | '\t'))
+    }
+
+    /// Determines whether a line of Markdown is a link reference definition
+    /// (e.g., "[ref]: https://example.com").
+    fn is_markdown_link_reference_definition(content: &str) -> bool {
+        content.starts_with('[') && content.contains("]:")
+    }
+}
@@ -5,5 +5,5 @@ pub struct ResourceContainer;
 
 impl ResourceContainer {
 
-    pub const COMPRESSED_COMPANY_SYMBOL_LIST_BYTE_ARRAY: &'static [u8] = include_bytes!("../../embedded_storage/COMPRESSED_COMPANY_SYMBOL_LIST_BYTE_ARRAY.bin");
+    pub const SYMBOL_INDEX_BYTE_ARRAY: &'static [u8] = include_bytes!("../../embedded_storage/SYMBOL_INDEX_BYTE_ARRAY.bin");
 }
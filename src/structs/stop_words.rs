@@ -0,0 +1,207 @@
+use crate::constants::{FRENCH_STOP_WORDS, GERMAN_STOP_WORDS, SPANISH_STOP_WORDS, STOP_WORDS};
+use crate::Error;
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+/// A predefined list of stop words which a `StopWords` set starts from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StopWordPreset {
+    /// No stop words, so that only explicitly added words are filtered.
+    None,
+
+    /// English stop words (e.g., "the" and "and").
+    #[default]
+    English,
+
+    /// French stop words (e.g., "le" and "et").
+    French,
+
+    /// German stop words (e.g., "der" and "und").
+    German,
+
+    /// Spanish stop words (e.g., "el" and "y").
+    Spanish,
+}
+
+impl StopWordPreset {
+    /// All presets, in declaration order.
+    pub const ALL: [StopWordPreset; 5] = [
+        StopWordPreset::None,
+        StopWordPreset::English,
+        StopWordPreset::French,
+        StopWordPreset::German,
+        StopWordPreset::Spanish,
+    ];
+
+    /// Returns a stable identifier for the preset (e.g., "english").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StopWordPreset::None => "none",
+            StopWordPreset::English => "english",
+            StopWordPreset::French => "french",
+            StopWordPreset::German => "german",
+            StopWordPreset::Spanish => "spanish",
+        }
+    }
+
+    /// Returns the stop words of the preset, in lowercase.
+    pub fn words(&self) -> &'static [&'static str] {
+        match self {
+            StopWordPreset::None => &[],
+            StopWordPreset::English => STOP_WORDS,
+            StopWordPreset::French => FRENCH_STOP_WORDS,
+            StopWordPreset::German => GERMAN_STOP_WORDS,
+            StopWordPreset::Spanish => SPANISH_STOP_WORDS,
+        }
+    }
+}
+
+impl fmt::Display for StopWordPreset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for StopWordPreset {
+    type Err = Error;
+
+    /// Parses a preset from its identifier, ignoring case (e.g., "English").
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        StopWordPreset::ALL
+            .into_iter()
+            .find(|preset| preset.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| Error::ParserError(format!("Unknown stop word preset '{}'", s)))
+    }
+}
+
+/// The words which are filtered from text documents, and from the company names
+/// they are matched against.
+///
+/// A set starts from a `StopWordPreset`, to which words can be added, or from
+/// which words can be removed. Words are compared ignoring case.
+///
+/// # Example
+/// ```
+/// use ticker_sniffer::{StopWordPreset, StopWords};
+///
+/// let stop_words = StopWords::new(StopWordPreset::English)
+///     .with_added_words(["inc"])
+///     .with_removed_words(["all"]);
+///
+/// assert!(stop_words.contains("The"));
+/// assert!(stop_words.contains("INC"));
+/// assert!(!stop_words.contains("all"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StopWords {
+    /// The preset the set starts from.
+    pub preset: StopWordPreset,
+
+    /// The words added to the preset.
+    pub added_words: Vec<String>,
+
+    /// The words removed from the preset, or from the added words.
+    pub removed_words: Vec<String>,
+}
+
+impl StopWords {
+    /// Creates a set of stop words from a preset.
+    ///
+    /// # Arguments
+    /// * `preset` - The preset the set starts from.
+    pub const fn new(preset: StopWordPreset) -> Self {
+        Self {
+            preset,
+            added_words: Vec::new(),
+            removed_words: Vec::new(),
+        }
+    }
+
+    /// Creates a set of stop words which does not start from any preset.
+    ///
+    /// # Arguments
+    /// * `words` - The stop words.
+    pub fn from_words<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self::new(StopWordPreset::None).with_added_words(words)
+    }
+
+    /// Adds words to the set.
+    ///
+    /// # Arguments
+    /// * `words` - The words to add.
+    pub fn with_added_words<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.added_words
+            .extend(words.into_iter().map(|word| word.as_ref().to_string()));
+        self
+    }
+
+    /// Removes words from the set.
+    ///
+    /// # Arguments
+    /// * `words` - The words to remove.
+    pub fn with_removed_words<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.removed_words
+            .extend(words.into_iter().map(|word| word.as_ref().to_string()));
+        self
+    }
+
+    /// Determines whether a word is a stop word, ignoring case.
+    ///
+    /// # Arguments
+    /// * `word` - The word to look up.
+    pub fn contains(&self, word: &str) -> bool {
+        self.to_uppercase_set().contains(&word.to_uppercase())
+    }
+
+    /// Validates that each added or removed word is a single, non-empty word.
+    ///
+    /// # Errors
+    /// Returns `Error::ConfigError` if a word is empty, or contains whitespace.
+    pub fn validate(&self) -> Result<(), Error> {
+        for word in self.added_words.iter().chain(&self.removed_words) {
+            if word.trim().is_empty() || word.contains(char::is_whitespace) {
+                return Err(Error::ConfigError(format!(
+                    "Stop words must be single, non-empty words, got '{}'",
+                    word
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Resolves the set into uppercase words, as they are compared against tokens.
+    pub fn to_uppercase_set(&self) -> HashSet<String> {
+        let removed_words: HashSet<String> = self
+            .removed_words
+            .iter()
+            .map(|word| word.to_uppercase())
+            .collect();
+
+        self.preset
+            .words()
+            .iter()
+            .copied()
+            .chain(self.added_words.iter().map(String::as_str))
+            .map(|word| word.to_uppercase())
+            .filter(|word| !removed_words.contains(word))
+            .collect()
+    }
+}
@@ -0,0 +1,731 @@
+use crate::config::DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG;
+use crate::types::{CompanySymbolList, TickerSymbolTokenId, TokenId, TokenVector};
+use crate::{
+    AssetType, CompanyMetadata, CompanyTokenMapper, CompanyTokenProcessorConfig, Date, Error,
+    Market, TokenMapper, Tokenizer,
+};
+use std::collections::HashMap;
+
+/// Identifies the start of a serialized symbol index.
+const MAGIC_BYTES: &[u8; 4] = b"TSIX";
+
+/// The version of the serialized format, incremented whenever it changes.
+const FORMAT_VERSION: u8 = 2;
+
+/// A company symbol list, along with its token maps, in a form which can be
+/// serialized and loaded without parsing or tokenizing the company symbol list.
+///
+/// The token maps are built with the entry filters of
+/// `DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG`. Configurations which include the same
+/// entries reuse them as-is, while other configurations rebuild them from the
+/// company symbol list stored in the index.
+///
+/// The embedded symbol index is generated from `data/company_symbol_list.csv`
+/// with `cargo run -p dev --bin build_symbol_index`.
+///
+/// # Example
+/// ```
+/// use ticker_sniffer::{
+///     CompanyMetadata, SymbolIndex, TickerSniffer, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+/// };
+///
+/// let symbol_index =
+///     SymbolIndex::new(vec![CompanyMetadata::new("IBM", Some("IBM"), &["Big Blue"])]).unwrap();
+///
+/// let bytes = symbol_index.to_bytes();
+/// let symbol_index = SymbolIndex::from_bytes(&bytes).unwrap();
+///
+/// let ticker_sniffer =
+///     TickerSniffer::with_symbol_index(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, symbol_index, true)
+///         .unwrap();
+///
+/// let results = ticker_sniffer.extract("Big Blue reported earnings.").unwrap();
+/// assert_eq!(results.get("IBM"), Some(&1));
+/// ```
+pub struct SymbolIndex {
+    company_symbol_list: CompanySymbolList,
+    company_token_mapper: CompanyTokenMapper,
+}
+
+impl SymbolIndex {
+    /// Creates a symbol index by building the token maps for a company symbol list.
+    ///
+    /// # Arguments
+    /// * `company_symbol_list` - The list of company symbols to index.
+    ///
+    /// # Errors
+    /// Returns an error if the token maps cannot be built.
+    pub fn new(company_symbol_list: CompanySymbolList) -> Result<Self, Error> {
+        let company_token_mapper = CompanyTokenMapper::with_config(
+            &company_symbol_list,
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+            true,
+        )?;
+
+        Ok(SymbolIndex {
+            company_symbol_list,
+            company_token_mapper,
+        })
+    }
+
+    /// Returns the company symbol list stored in the index.
+    pub fn company_symbol_list(&self) -> &CompanySymbolList {
+        &self.company_symbol_list
+    }
+
+    /// Converts the index into a company token mapper for the provided configuration.
+    ///
+    /// The stored token maps are reused if the configuration includes the same
+    /// entries, filters the same stop words, and normalizes text the same way as
+    /// the default configuration, and are otherwise rebuilt.
+    ///
+    /// # Arguments
+    /// * `config` - A reference to the configuration for processing tokens.
+    /// * `is_case_sensitive` - Whether or not text documents should be filtered using case sensitivity.
+    ///
+    /// # Errors
+    /// Returns an error if the token maps need to be rebuilt, and cannot be.
+    pub(crate) fn into_company_token_mapper(
+        self,
+        config: &CompanyTokenProcessorConfig,
+        is_case_sensitive: bool,
+    ) -> Result<CompanyTokenMapper, Error> {
+        if config.has_same_token_maps(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG) {
+            Ok(self
+                .company_token_mapper
+                .with_tokenizers(config, is_case_sensitive))
+        } else {
+            CompanyTokenMapper::with_config(&self.company_symbol_list, config, is_case_sensitive)
+        }
+    }
+
+    /// Serializes the index into a compact binary form.
+    ///
+    /// The output is deterministic, so an unchanged company symbol list always
+    /// serializes to the same bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut writer = IndexWriter::default();
+
+        writer.bytes.extend_from_slice(MAGIC_BYTES);
+        writer.bytes.push(FORMAT_VERSION);
+
+        writer.write_company_symbol_list(&self.company_symbol_list);
+
+        let company_token_mapper = &self.company_token_mapper;
+        let token_mapper = &company_token_mapper.token_mapper;
+
+        let reverse_token_map = &token_mapper.reverse_token_map;
+        writer.write_usize(reverse_token_map.len());
+        for token_id in 0..reverse_token_map.len() {
+            writer.write_token_vector(&reverse_token_map[&token_id]);
+        }
+
+        // The token IDs of each word of the company names are written once, so
+        // that the token IDs of company names which tokenize word by word can be
+        // derived from their words, rather than being written out
+        let word_token_ids: Vec<Vec<TokenId>> = writer
+            .words
+            .iter()
+            .map(|word| {
+                company_token_mapper
+                    .text_doc_tokenizer
+                    .tokenize(&word.to_uppercase())
+                    .iter()
+                    .map(|token| token_mapper.get_token_id(token))
+                    .collect::<Option<Vec<TokenId>>>()
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        for token_ids in &word_token_ids {
+            writer.write_token_ids(token_ids);
+        }
+
+        // The token IDs of each ingested entry are written in the order they were
+        // ingested, so that the token maps can be rebuilt by replaying the
+        // ingestion without tokenizing the company symbol list
+        let mut company_token_sequence_offsets: HashMap<TickerSymbolTokenId, usize> =
+            HashMap::new();
+
+        for company_metadata in Self::included_company_metadata(&self.company_symbol_list) {
+            let ticker_symbol_token_ids: Vec<TokenId> = company_token_mapper
+                .ticker_symbol_tokenizer
+                .tokenize(&company_metadata.ticker_symbol)
+                .iter()
+                .filter_map(|token| token_mapper.get_token_id(token))
+                .collect();
+            writer.write_token_ids(&ticker_symbol_token_ids);
+
+            let Some(ticker_symbol_token_id) = ticker_symbol_token_ids.last() else {
+                continue;
+            };
+
+            let offset = company_token_sequence_offsets
+                .entry(*ticker_symbol_token_id)
+                .or_default();
+            let company_token_sequence_count = Self::count_company_names(company_metadata);
+
+            let company_token_sequences = company_token_mapper
+                .company_token_sequences_map
+                .get(ticker_symbol_token_id)
+                .map(|company_token_sequences| {
+                    &company_token_sequences[*offset..*offset + company_token_sequence_count]
+                })
+                .unwrap_or_default();
+
+            let company_names = company_metadata
+                .company_name
+                .iter()
+                .chain(&company_metadata.alternate_company_names);
+
+            for (company_name, company_token_sequence) in company_names.zip(company_token_sequences)
+            {
+                let derived_token_ids: Vec<TokenId> = company_name
+                    .split(' ')
+                    .flat_map(|word| &word_token_ids[writer.word_ids[word]])
+                    .copied()
+                    .collect();
+
+                if &derived_token_ids == company_token_sequence {
+                    writer.bytes.push(0);
+                } else {
+                    writer.bytes.push(1);
+                    writer.write_token_ids(company_token_sequence);
+                }
+            }
+
+            *offset += company_token_sequence_count;
+        }
+
+        writer.bytes
+    }
+
+    /// Deserializes an index which was serialized with `to_bytes`.
+    ///
+    /// # Arguments
+    /// * `bytes` - The serialized index.
+    ///
+    /// # Errors
+    /// Returns `Error::SymbolIndexError` if the bytes are not a valid index, or
+    /// were serialized with a different format version.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut reader = IndexReader::new(bytes)?;
+
+        let company_symbol_list = reader.read_company_symbol_list()?;
+
+        let token_count = reader.read_len()?;
+        let mut tokens = Vec::with_capacity(token_count);
+        for _ in 0..token_count {
+            tokens.push(reader.read_token_vector()?);
+        }
+
+        let mut word_token_ids = Vec::with_capacity(reader.words.len());
+        for _ in 0..reader.words.len() {
+            word_token_ids.push(reader.read_token_ids(token_count)?);
+        }
+
+        let mut ticker_symbol_map = HashMap::with_capacity(company_symbol_list.len());
+        let mut reverse_ticker_symbol_map = HashMap::with_capacity(company_symbol_list.len());
+        let mut company_token_sequences_map: HashMap<TickerSymbolTokenId, Vec<Vec<TokenId>>> =
+            HashMap::with_capacity(company_symbol_list.len());
+        let mut company_reverse_token_map: HashMap<TokenId, Vec<TickerSymbolTokenId>> =
+            HashMap::new();
+        let mut company_metadata_map = HashMap::with_capacity(company_symbol_list.len());
+
+        // Replay the ingestion of `CompanyTokenMapper`, using the stored token IDs
+        let company_name_word_ids = std::mem::take(&mut reader.company_name_word_ids);
+        let mut company_name_offset = 0;
+
+        for company_metadata in &company_symbol_list {
+            let company_name_count = Self::count_company_names(company_metadata);
+            let company_names_word_ids = &company_name_word_ids
+                [company_name_offset..company_name_offset + company_name_count];
+            company_name_offset += company_name_count;
+
+            if !CompanyTokenMapper::is_included(
+                company_metadata,
+                DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+            ) {
+                continue;
+            }
+
+            let ticker_symbol = &company_metadata.ticker_symbol;
+
+            let ticker_symbol_token_ids = reader.read_token_ids(token_count)?;
+            for ticker_symbol_token_id in &ticker_symbol_token_ids {
+                ticker_symbol_map.insert(ticker_symbol.clone(), *ticker_symbol_token_id);
+                reverse_ticker_symbol_map.insert(*ticker_symbol_token_id, ticker_symbol.clone());
+            }
+
+            let ticker_symbol_token_id = *ticker_symbol_token_ids.last().ok_or_else(|| {
+                Error::SymbolIndexError(format!("Missing token IDs for '{}'", ticker_symbol))
+            })?;
+
+            for word_ids in company_names_word_ids {
+                let company_token_sequence = match reader.read_u8()? {
+                    0 => word_ids
+                        .iter()
+                        .flat_map(|word_id| &word_token_ids[*word_id])
+                        .copied()
+                        .collect(),
+                    _ => reader.read_token_ids(token_count)?,
+                };
+
+                for token_id in &company_token_sequence {
+                    company_reverse_token_map
+                        .entry(*token_id)
+                        .or_default()
+                        .push(ticker_symbol_token_id);
+                }
+
+                company_token_sequences_map
+                    .entry(ticker_symbol_token_id)
+                    .or_default()
+                    .push(company_token_sequence);
+            }
+
+            company_metadata_map.insert(ticker_symbol.clone(), company_metadata.clone());
+        }
+
+        if !reader.is_empty() {
+            return Err(Error::SymbolIndexError(
+                "Unexpected data after the end of the index".to_string(),
+            ));
+        }
+
+        let company_token_mapper = CompanyTokenMapper {
+            token_mapper: TokenMapper::with_tokens(tokens),
+            ticker_symbol_tokenizer: Tokenizer::ticker_symbol_parser(true),
+            text_doc_tokenizer: Tokenizer::text_doc_parser(true),
+            cashtag_tokenizer: Tokenizer::cashtag_parser(),
+            exchange_prefixed_tokenizer: Tokenizer::exchange_prefixed_parser(true),
+            ticker_symbol_map,
+            reverse_ticker_symbol_map,
+            company_token_sequences_map,
+            company_reverse_token_map,
+            company_metadata_map,
+        }
+        .with_tokenizers(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true);
+
+        Ok(SymbolIndex {
+            company_symbol_list,
+            company_token_mapper,
+        })
+    }
+
+    /// Deserializes only the company symbol list of an index which was serialized
+    /// with `to_bytes`, without reading its token maps.
+    ///
+    /// # Arguments
+    /// * `bytes` - The serialized index.
+    ///
+    /// # Errors
+    /// Returns `Error::SymbolIndexError` if the bytes are not a valid index, or
+    /// were serialized with a different format version.
+    pub fn read_company_symbol_list(bytes: &[u8]) -> Result<CompanySymbolList, Error> {
+        IndexReader::new(bytes)?.read_company_symbol_list()
+    }
+
+    /// Returns the entries of a company symbol list which are ingested with the
+    /// default configuration, in the order they are ingested.
+    fn included_company_metadata(
+        company_symbol_list: &CompanySymbolList,
+    ) -> impl Iterator<Item = &CompanyMetadata> {
+        company_symbol_list.iter().filter(|company_metadata| {
+            CompanyTokenMapper::is_included(
+                company_metadata,
+                DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+            )
+        })
+    }
+
+    /// Counts the company names of an entry, each of which is ingested as a
+    /// company token sequence.
+    fn count_company_names(company_metadata: &CompanyMetadata) -> usize {
+        usize::from(company_metadata.company_name.is_some())
+            + company_metadata.alternate_company_names.len()
+    }
+}
+
+/// Writes the primitive values of a serialized symbol index.
+///
+/// Integers are written as LEB128 variable-length integers, and strings are
+/// written as their UTF-8 length followed by their bytes. Company names are
+/// written as the IDs of their words in a dictionary, as most of their words
+/// are shared with other company names, and tokens which are an uppercased
+/// dictionary word are written as the ID of that word.
+#[derive(Default)]
+struct IndexWriter {
+    bytes: Vec<u8>,
+    words: Vec<String>,
+    word_ids: HashMap<String, usize>,
+    uppercase_word_ids: HashMap<String, usize>,
+}
+
+impl IndexWriter {
+    fn write_usize(&mut self, mut value: usize) {
+        while value >= 0x80 {
+            self.bytes.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        self.bytes.push(value as u8);
+    }
+
+    fn write_str(&mut self, value: &str) {
+        self.write_usize(value.len());
+        self.bytes.extend_from_slice(value.as_bytes());
+    }
+
+    fn write_optional_str(&mut self, value: Option<&str>) {
+        match value {
+            Some(value) => {
+                self.bytes.push(1);
+                self.write_str(value);
+            }
+            None => self.bytes.push(0),
+        }
+    }
+
+    /// Writes a dictionary of the words of the company names, ordered by how often
+    /// they occur, such that the most common words have the shortest IDs.
+    fn write_company_name_dictionary(&mut self, company_symbol_list: &CompanySymbolList) {
+        let mut word_counts: HashMap<&str, usize> = HashMap::new();
+        for company_metadata in company_symbol_list {
+            for company_name in company_metadata
+                .company_name
+                .iter()
+                .chain(&company_metadata.alternate_company_names)
+            {
+                for word in company_name.split(' ') {
+                    *word_counts.entry(word).or_default() += 1;
+                }
+            }
+        }
+
+        let mut words: Vec<(&str, usize)> = word_counts.into_iter().collect();
+        words.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+
+        self.write_usize(words.len());
+        for (word_id, (word, _)) in words.into_iter().enumerate() {
+            self.write_str(word);
+            self.words.push(word.to_string());
+            self.word_ids.insert(word.to_string(), word_id);
+            self.uppercase_word_ids
+                .entry(word.to_uppercase())
+                .or_insert(word_id);
+        }
+    }
+
+    fn write_company_name(&mut self, company_name: &str) {
+        let words: Vec<&str> = company_name.split(' ').collect();
+
+        self.write_usize(words.len());
+        for word in words {
+            let word_id = self.word_ids[word];
+            self.write_usize(word_id);
+        }
+    }
+
+    fn write_optional_company_name(&mut self, company_name: Option<&str>) {
+        match company_name {
+            Some(company_name) => {
+                self.bytes.push(1);
+                self.write_company_name(company_name);
+            }
+            None => self.bytes.push(0),
+        }
+    }
+
+    fn write_optional_date(&mut self, value: Option<Date>) {
+        match value {
+            Some(date) => {
+                self.bytes.push(1);
+                self.write_usize(date.year() as usize);
+                self.bytes.push(date.month());
+                self.bytes.push(date.day());
+            }
+            None => self.bytes.push(0),
+        }
+    }
+
+    /// Writes a token as the ID of the dictionary word it is the uppercase of,
+    /// offset by one, or as `0` followed by the token itself.
+    fn write_token_vector(&mut self, token_vector: &TokenVector) {
+        let token = Tokenizer::charcode_vector_to_token(token_vector);
+
+        match self.uppercase_word_ids.get(&token) {
+            Some(word_id) => self.write_usize(word_id + 1),
+            None => {
+                self.write_usize(0);
+                self.write_str(&token);
+            }
+        }
+    }
+
+    fn write_token_ids(&mut self, token_ids: &[TokenId]) {
+        self.write_usize(token_ids.len());
+        for token_id in token_ids {
+            self.write_usize(*token_id);
+        }
+    }
+
+    fn write_company_symbol_list(&mut self, company_symbol_list: &CompanySymbolList) {
+        self.write_company_name_dictionary(company_symbol_list);
+
+        self.write_usize(company_symbol_list.len());
+
+        for company_metadata in company_symbol_list {
+            self.write_str(&company_metadata.ticker_symbol);
+            self.write_optional_company_name(company_metadata.company_name.as_deref());
+
+            self.write_usize(company_metadata.alternate_company_names.len());
+            for alternate_company_name in &company_metadata.alternate_company_names {
+                self.write_company_name(alternate_company_name);
+            }
+
+            // Enums are written as their position in `ALL`, with asset types
+            // offset by one to represent `None`
+            let asset_type_idx = company_metadata.asset_type.map_or(0, |asset_type| {
+                AssetType::ALL
+                    .iter()
+                    .position(|candidate| *candidate == asset_type)
+                    .map_or(0, |idx| idx + 1)
+            });
+            self.write_usize(asset_type_idx);
+
+            let market_idx = Market::ALL
+                .iter()
+                .position(|market| *market == company_metadata.market)
+                .unwrap_or_default();
+            self.write_usize(market_idx);
+
+            self.write_optional_date(company_metadata.valid_from);
+            self.write_optional_date(company_metadata.valid_until);
+            self.write_optional_str(company_metadata.successor_ticker_symbol.as_deref());
+        }
+    }
+}
+
+/// Reads the primitive values of a serialized symbol index.
+struct IndexReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    words: Vec<String>,
+    company_name_word_ids: Vec<Vec<usize>>,
+}
+
+impl<'a> IndexReader<'a> {
+    /// Creates a reader, validating the header of the serialized index.
+    fn new(bytes: &'a [u8]) -> Result<Self, Error> {
+        let mut reader = IndexReader {
+            bytes,
+            position: 0,
+            words: Vec::new(),
+            company_name_word_ids: Vec::new(),
+        };
+
+        if reader.read_bytes(MAGIC_BYTES.len())? != MAGIC_BYTES {
+            return Err(Error::SymbolIndexError(
+                "The data is not a symbol index".to_string(),
+            ));
+        }
+
+        let format_version = reader.read_u8()?;
+        if format_version != FORMAT_VERSION {
+            return Err(Error::SymbolIndexError(format!(
+                "Unsupported format version {}, expected {}",
+                format_version, FORMAT_VERSION
+            )));
+        }
+
+        Ok(reader)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position == self.bytes.len()
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        let end = self
+            .position
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(|| Error::SymbolIndexError("Unexpected end of the index".to_string()))?;
+
+        let bytes = &self.bytes[self.position..end];
+        self.position = end;
+
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, Error> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_usize(&mut self) -> Result<usize, Error> {
+        let mut value: usize = 0;
+
+        for shift in (0..usize::BITS).step_by(7) {
+            let byte = self.read_u8()?;
+            value |= ((byte & 0x7f) as usize) << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        Err(Error::SymbolIndexError("Integer overflow".to_string()))
+    }
+
+    /// Reads the number of elements which follow. As each element takes at least
+    /// one byte, a length greater than the number of remaining bytes is invalid,
+    /// which prevents corrupt lengths from preallocating excessive memory.
+    fn read_len(&mut self) -> Result<usize, Error> {
+        let len = self.read_usize()?;
+
+        if len > self.bytes.len() - self.position {
+            return Err(Error::SymbolIndexError(format!("Invalid length {}", len)));
+        }
+
+        Ok(len)
+    }
+
+    fn read_string(&mut self) -> Result<String, Error> {
+        let len = self.read_usize()?;
+        let bytes = self.read_bytes(len)?;
+
+        String::from_utf8(bytes.to_vec())
+            .map_err(|err| Error::SymbolIndexError(format!("Invalid string: {}", err)))
+    }
+
+    fn read_optional_string(&mut self) -> Result<Option<String>, Error> {
+        match self.read_u8()? {
+            0 => Ok(None),
+            _ => Ok(Some(self.read_string()?)),
+        }
+    }
+
+    fn read_optional_date(&mut self) -> Result<Option<Date>, Error> {
+        match self.read_u8()? {
+            0 => Ok(None),
+            _ => {
+                let year = u16::try_from(self.read_usize()?)
+                    .map_err(|_| Error::SymbolIndexError("Invalid year".to_string()))?;
+                let month = self.read_u8()?;
+                let day = self.read_u8()?;
+
+                Ok(Some(Date::new(year, month, day)?))
+            }
+        }
+    }
+
+    fn read_token_vector(&mut self) -> Result<TokenVector, Error> {
+        let token = match self.read_usize()? {
+            0 => self.read_string()?,
+            word_idx => self.get_word(word_idx - 1)?.to_uppercase(),
+        };
+
+        Ok(Tokenizer::token_to_charcode_vector(&token))
+    }
+
+    /// Reads token IDs, validating that each is less than the number of tokens.
+    fn read_token_ids(&mut self, token_count: usize) -> Result<Vec<TokenId>, Error> {
+        let len = self.read_len()?;
+        let mut token_ids = Vec::with_capacity(len);
+        for _ in 0..len {
+            let token_id = self.read_usize()?;
+            if token_id >= token_count {
+                return Err(Error::SymbolIndexError(format!(
+                    "Invalid token ID {}",
+                    token_id
+                )));
+            }
+            token_ids.push(token_id);
+        }
+
+        Ok(token_ids)
+    }
+
+    fn get_word(&self, word_id: usize) -> Result<&str, Error> {
+        self.words
+            .get(word_id)
+            .map(String::as_str)
+            .ok_or_else(|| Error::SymbolIndexError(format!("Invalid word ID {}", word_id)))
+    }
+
+    /// Reads a company name, keeping the IDs of its words for deriving its
+    /// token IDs.
+    fn read_company_name(&mut self) -> Result<String, Error> {
+        let len = self.read_len()?;
+        let mut word_ids = Vec::with_capacity(len);
+        for _ in 0..len {
+            let word_id = self.read_usize()?;
+            self.get_word(word_id)?;
+            word_ids.push(word_id);
+        }
+
+        let company_name = word_ids
+            .iter()
+            .map(|word_id| self.words[*word_id].as_str())
+            .collect::<Vec<&str>>()
+            .join(" ");
+        self.company_name_word_ids.push(word_ids);
+
+        Ok(company_name)
+    }
+
+    fn read_company_symbol_list(&mut self) -> Result<CompanySymbolList, Error> {
+        let word_count = self.read_len()?;
+        self.words = Vec::with_capacity(word_count);
+        for _ in 0..word_count {
+            let word = self.read_string()?;
+            self.words.push(word);
+        }
+
+        let len = self.read_len()?;
+        let mut company_symbol_list = Vec::with_capacity(len);
+
+        for _ in 0..len {
+            let ticker_symbol = self.read_string()?;
+            let company_name = match self.read_u8()? {
+                0 => None,
+                _ => Some(self.read_company_name()?),
+            };
+
+            let alternate_company_name_count = self.read_len()?;
+            let mut alternate_company_names = Vec::with_capacity(alternate_company_name_count);
+            for _ in 0..alternate_company_name_count {
+                alternate_company_names.push(self.read_company_name()?);
+            }
+
+            let asset_type = match self.read_usize()? {
+                0 => None,
+                idx => Some(*AssetType::ALL.get(idx - 1).ok_or_else(|| {
+                    Error::SymbolIndexError(format!("Invalid asset type {}", idx))
+                })?),
+            };
+
+            let market_idx = self.read_usize()?;
+            let market = *Market::ALL
+                .get(market_idx)
+                .ok_or_else(|| Error::SymbolIndexError(format!("Invalid market {}", market_idx)))?;
+
+            let valid_from = self.read_optional_date()?;
+            let valid_until = self.read_optional_date()?;
+            let successor_ticker_symbol = self.read_optional_string()?;
+
+            company_symbol_list.push(CompanyMetadata {
+                ticker_symbol,
+                company_name,
+                alternate_company_names,
+                asset_type,
+                market,
+                valid_from,
+                valid_until,
+                successor_ticker_symbol,
+            });
+        }
+
+        Ok(company_symbol_list)
+    }
+}
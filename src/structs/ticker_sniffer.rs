@@ -0,0 +1,683 @@
+use crate::structs::ResourceContainer;
+use crate::types::{CompanySymbolList, TickerSymbol, TickerSymbolFrequencyMap};
+#[cfg(feature = "csv")]
+use crate::CompanySymbolListPreprocessor;
+use crate::{
+    CompanyMetadata, CompanyNameConsistencyIssue, CompanyNameConsistencyIssueKind,
+    CompanySymbolListOverlay, CompanyTokenProcessor, CompanyTokenProcessorConfig, Error,
+    ExtractionTrace, MarkupFormat, MarkupText, SymbolIndex, TickerSymbolMention,
+    TickerSymbolResult, TokenSequenceCollision,
+};
+#[cfg(feature = "compressed-symbol-index")]
+use flate2::read::GzDecoder;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use std::borrow::Cow;
+use std::collections::HashSet;
+#[cfg(any(feature = "csv", feature = "compressed-symbol-index"))]
+use std::io::Read;
+#[cfg(feature = "csv")]
+use std::path::Path;
+
+/// A reusable engine for extracting ticker symbols from text documents.
+///
+/// Building the symbol index requires parsing and tokenizing the company symbol
+/// list, which is considerably more expensive than processing a typical text
+/// document. The embedded company symbol list is prebuilt into a `SymbolIndex`
+/// at build time, which avoids most of this work. A `TickerSniffer` loads or
+/// builds its symbol index once, owns its configuration, and is `Send + Sync`,
+/// so a single instance can be shared across threads to process any number of
+/// documents.
+///
+/// # Example
+/// ```
+/// use ticker_sniffer::{TickerSniffer, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG};
+///
+/// let ticker_sniffer = TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+///
+/// let results = ticker_sniffer.extract("Apple and Microsoft").unwrap();
+/// assert_eq!(results.get("AAPL"), Some(&1));
+/// assert_eq!(results.get("MSFT"), Some(&1));
+/// ```
+pub struct TickerSniffer {
+    company_token_processor: CompanyTokenProcessor,
+}
+
+impl TickerSniffer {
+    /// Creates a new `TickerSniffer` using the embedded company symbol list.
+    ///
+    /// # Arguments
+    /// * `config` - A reference to the configuration for processing tokens.
+    /// * `is_case_sensitive` - Whether or not text documents should be filtered using case sensitivity.
+    ///
+    /// # Errors
+    /// Returns an error if the embedded symbol index cannot be read, or if the
+    /// configuration is invalid.
+    pub fn new(
+        config: &CompanyTokenProcessorConfig,
+        is_case_sensitive: bool,
+    ) -> Result<Self, Error> {
+        let symbol_index = SymbolIndex::from_bytes(&Self::embedded_symbol_index_bytes()?)?;
+
+        Self::with_symbol_index(config, symbol_index, is_case_sensitive)
+    }
+
+    /// Creates a new `TickerSniffer` using a prebuilt symbol index.
+    ///
+    /// The token maps of the index are reused if the configuration includes the
+    /// same entries as `DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG` (i.e., the same
+    /// asset types, markets, and as-of date), and are otherwise rebuilt from the
+    /// company symbol list stored in the index.
+    ///
+    /// # Arguments
+    /// * `config` - A reference to the configuration for processing tokens.
+    /// * `symbol_index` - The symbol index.
+    /// * `is_case_sensitive` - Whether or not text documents should be filtered using case sensitivity.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is invalid, or if the symbol index
+    /// needs to be rebuilt, and cannot be.
+    pub fn with_symbol_index(
+        config: &CompanyTokenProcessorConfig,
+        symbol_index: SymbolIndex,
+        is_case_sensitive: bool,
+    ) -> Result<Self, Error> {
+        config.validate()?;
+
+        let company_token_mapper =
+            symbol_index.into_company_token_mapper(config, is_case_sensitive)?;
+
+        let company_token_processor =
+            CompanyTokenProcessor::with_company_token_mapper(config, company_token_mapper)?;
+
+        Ok(TickerSniffer {
+            company_token_processor,
+        })
+    }
+
+    /// Creates a new `TickerSniffer` using the embedded company symbol list, with
+    /// the provided overlay applied on top of it.
+    ///
+    /// # Arguments
+    /// * `config` - A reference to the configuration for processing tokens.
+    /// * `overlay` - A reference to the patches to apply to the embedded company symbol list.
+    /// * `is_case_sensitive` - Whether or not text documents should be filtered using case sensitivity.
+    ///
+    /// # Errors
+    /// Returns an error if the embedded symbol index cannot be read, or if the
+    /// symbol index cannot be built.
+    ///
+    /// # Example
+    /// ```
+    /// use ticker_sniffer::{
+    ///     CompanyMetadata, CompanySymbolListOverlay, CompanySymbolListPatch, TickerSniffer,
+    ///     DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+    /// };
+    ///
+    /// let overlay = CompanySymbolListOverlay::new(vec![CompanySymbolListPatch::Add(
+    ///     CompanyMetadata::new("IBM", None, &["Big Blue"]),
+    /// )]);
+    ///
+    /// let ticker_sniffer =
+    ///     TickerSniffer::with_overlay(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, &overlay, true).unwrap();
+    ///
+    /// let results = ticker_sniffer.extract("Big Blue reported earnings.").unwrap();
+    /// assert_eq!(results.get("IBM"), Some(&1));
+    /// ```
+    pub fn with_overlay(
+        config: &CompanyTokenProcessorConfig,
+        overlay: &CompanySymbolListOverlay,
+        is_case_sensitive: bool,
+    ) -> Result<Self, Error> {
+        let mut company_symbol_list =
+            SymbolIndex::read_company_symbol_list(&Self::embedded_symbol_index_bytes()?)?;
+
+        overlay.apply(&mut company_symbol_list);
+
+        Self::with_company_symbol_list(config, &company_symbol_list, is_case_sensitive)
+    }
+
+    /// Returns the bytes of the embedded symbol index, decompressing them if the
+    /// `compressed-symbol-index` feature is enabled.
+    ///
+    /// # Errors
+    /// Returns `Error::DecompressionError` if the embedded symbol index cannot be
+    /// decompressed.
+    fn embedded_symbol_index_bytes() -> Result<Cow<'static, [u8]>, Error> {
+        #[cfg(feature = "compressed-symbol-index")]
+        {
+            let mut decoder = GzDecoder::new(ResourceContainer::SYMBOL_INDEX_BYTE_ARRAY);
+            let mut bytes = Vec::new();
+            decoder
+                .read_to_end(&mut bytes)
+                .map_err(Error::DecompressionError)?;
+
+            Ok(Cow::Owned(bytes))
+        }
+
+        #[cfg(not(feature = "compressed-symbol-index"))]
+        {
+            Ok(Cow::Borrowed(ResourceContainer::SYMBOL_INDEX_BYTE_ARRAY))
+        }
+    }
+
+    /// Creates a new `TickerSniffer` using the provided company symbol list.
+    ///
+    /// # Arguments
+    /// * `config` - A reference to the configuration for processing tokens.
+    /// * `company_symbol_list` - A reference to the list of company symbols to index.
+    /// * `is_case_sensitive` - Whether or not text documents should be filtered using case sensitivity.
+    ///
+    /// # Errors
+    /// Returns an error if the symbol index cannot be built.
+    pub fn with_company_symbol_list(
+        config: &CompanyTokenProcessorConfig,
+        company_symbol_list: &CompanySymbolList,
+        is_case_sensitive: bool,
+    ) -> Result<Self, Error> {
+        let company_token_processor =
+            CompanyTokenProcessor::new(config, company_symbol_list, is_case_sensitive)?;
+
+        Ok(TickerSniffer {
+            company_token_processor,
+        })
+    }
+
+    /// Creates a new `TickerSniffer` using a company symbol list read from a CSV file.
+    ///
+    /// The file is expected to have the same columns as the embedded company
+    /// symbol list (`Symbol`, `Company Name`, and `Alternate Names`). Gzip-compressed
+    /// files are detected and decompressed automatically.
+    ///
+    /// # Arguments
+    /// * `config` - A reference to the configuration for processing tokens.
+    /// * `path` - The path to the CSV file.
+    /// * `is_case_sensitive` - Whether or not text documents should be filtered using case sensitivity.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or parsed, or if the symbol
+    /// index cannot be built.
+    #[cfg(feature = "csv")]
+    pub fn with_csv_path<P: AsRef<Path>>(
+        config: &CompanyTokenProcessorConfig,
+        path: P,
+        is_case_sensitive: bool,
+    ) -> Result<Self, Error> {
+        let company_symbol_list =
+            CompanySymbolListPreprocessor::read_company_symbol_list_from_path(path)?;
+
+        Self::with_company_symbol_list(config, &company_symbol_list, is_case_sensitive)
+    }
+
+    /// Creates a new `TickerSniffer` using a company symbol list read from
+    /// uncompressed CSV data.
+    ///
+    /// # Arguments
+    /// * `config` - A reference to the configuration for processing tokens.
+    /// * `reader` - A reader which yields CSV data.
+    /// * `is_case_sensitive` - Whether or not text documents should be filtered using case sensitivity.
+    ///
+    /// # Errors
+    /// Returns an error if the CSV data cannot be parsed, or if the symbol index
+    /// cannot be built.
+    ///
+    /// # Example
+    /// ```
+    /// use ticker_sniffer::{TickerSniffer, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG};
+    ///
+    /// let csv = "Symbol,Company Name,Alternate Names\nACME,Acme Corporation,\"Acme, Acme Corp\"\n";
+    ///
+    /// let ticker_sniffer =
+    ///     TickerSniffer::with_csv_reader(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, csv.as_bytes(), true)
+    ///         .unwrap();
+    ///
+    /// let results = ticker_sniffer.extract("Shares of Acme Corp rose.").unwrap();
+    /// assert_eq!(results.get("ACME"), Some(&1));
+    /// ```
+    #[cfg(feature = "csv")]
+    pub fn with_csv_reader<R: Read>(
+        config: &CompanyTokenProcessorConfig,
+        reader: R,
+        is_case_sensitive: bool,
+    ) -> Result<Self, Error> {
+        let company_symbol_list =
+            CompanySymbolListPreprocessor::read_company_symbol_list_from_reader(reader)?;
+
+        Self::with_company_symbol_list(config, &company_symbol_list, is_case_sensitive)
+    }
+
+    /// Creates a new `TickerSniffer` using a company symbol list read from
+    /// Gzip-compressed CSV data.
+    ///
+    /// # Arguments
+    /// * `config` - A reference to the configuration for processing tokens.
+    /// * `reader` - A reader which yields Gzip-compressed CSV data.
+    /// * `is_case_sensitive` - Whether or not text documents should be filtered using case sensitivity.
+    ///
+    /// # Errors
+    /// Returns an error if the data cannot be decompressed or parsed, or if the
+    /// symbol index cannot be built.
+    #[cfg(feature = "csv")]
+    pub fn with_gzip_csv_reader<R: Read>(
+        config: &CompanyTokenProcessorConfig,
+        reader: R,
+        is_case_sensitive: bool,
+    ) -> Result<Self, Error> {
+        let company_symbol_list =
+            CompanySymbolListPreprocessor::extract_company_symbol_list_from_reader(reader)?;
+
+        Self::with_company_symbol_list(config, &company_symbol_list, is_case_sensitive)
+    }
+
+    /// Returns the configuration used for processing tokens.
+    pub fn config(&self) -> &CompanyTokenProcessorConfig {
+        self.company_token_processor.config()
+    }
+
+    /// Retrieves the company name and alternate names for the given ticker symbol.
+    ///
+    /// # Arguments
+    /// * `ticker_symbol` - The ticker symbol to look up. The lookup is case insensitive.
+    ///
+    /// # Example
+    /// ```
+    /// use ticker_sniffer::{TickerSniffer, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG};
+    ///
+    /// let ticker_sniffer = TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+    ///
+    /// let company_metadata = ticker_sniffer.get_company_metadata("MSFT").unwrap();
+    /// assert_eq!(company_metadata.company_name.as_deref(), Some("Microsoft Corporation"));
+    /// ```
+    pub fn get_company_metadata(&self, ticker_symbol: &str) -> Option<&CompanyMetadata> {
+        self.company_token_processor
+            .company_token_mapper()
+            .get_company_metadata(ticker_symbol)
+    }
+
+    /// Searches for companies whose name or alternate names contain the query,
+    /// best match first.
+    ///
+    /// See `CompanyTokenMapper::search_company_metadata` for details on ranking.
+    ///
+    /// # Arguments
+    /// * `query` - The text to search for. The search is case insensitive.
+    pub fn search_company_metadata(&self, query: &str) -> Vec<&CompanyMetadata> {
+        self.company_token_processor
+            .company_token_mapper()
+            .search_company_metadata(query)
+    }
+
+    /// Collects the token sequences which can be matched to more than one ticker
+    /// symbol under the configured `threshold_min_company_token_coverage`, most
+    /// ambiguous first.
+    ///
+    /// See `CompanyTokenMapper::collect_token_sequence_collisions` for details on ranking.
+    ///
+    /// # Errors
+    /// Returns an error if a token ID or ticker symbol cannot be resolved.
+    ///
+    /// # Example
+    /// ```
+    /// use ticker_sniffer::{TickerSniffer, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG};
+    ///
+    /// let ticker_sniffer = TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+    ///
+    /// let collisions = ticker_sniffer.collect_token_sequence_collisions().unwrap();
+    /// let collision = collisions
+    ///     .iter()
+    ///     .find(|collision| collision.tokens.join(" ") == "FIDELITY BLUE CHIP")
+    ///     .unwrap();
+    ///
+    /// assert!(collision.matches.len() > 1);
+    /// ```
+    pub fn collect_token_sequence_collisions(&self) -> Result<Vec<TokenSequenceCollision>, Error> {
+        self.company_token_processor
+            .company_token_mapper()
+            .collect_token_sequence_collisions(self.config().threshold_min_company_token_coverage)
+    }
+
+    /// Extracts each company name and alternate name of the symbol list, and
+    /// reports the ones which do not extract to exactly their own ticker symbol.
+    ///
+    /// Only the entries included by the configuration are checked. Every name is
+    /// processed as a separate text document (in parallel if the `rayon` feature
+    /// is enabled), so checking the embedded symbol list takes a while.
+    ///
+    /// # Errors
+    /// Returns an error if processing a company name fails.
+    ///
+    /// # Returns
+    /// The issues, ordered by ticker symbol, then by the order of the names.
+    ///
+    /// # Example
+    /// ```
+    /// use ticker_sniffer::{
+    ///     CompanyMetadata, CompanyNameConsistencyIssueKind, TickerSniffer,
+    ///     DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+    /// };
+    ///
+    /// let company_symbol_list = vec![
+    ///     CompanyMetadata::new("ACME", Some("Acme Corporation"), &["The"]),
+    /// ];
+    ///
+    /// let ticker_sniffer = TickerSniffer::with_company_symbol_list(
+    ///     DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+    ///     &company_symbol_list,
+    ///     true,
+    /// )
+    /// .unwrap();
+    ///
+    /// let issues = ticker_sniffer.check_company_name_consistency().unwrap();
+    ///
+    /// assert_eq!(issues.len(), 1);
+    /// assert_eq!(issues[0].kind, CompanyNameConsistencyIssueKind::Unresolved);
+    /// assert_eq!(issues[0].company_name, "The");
+    /// ```
+    pub fn check_company_name_consistency(
+        &self,
+    ) -> Result<Vec<CompanyNameConsistencyIssue>, Error> {
+        let mut company_metadata_list: Vec<&CompanyMetadata> = self
+            .company_token_processor
+            .company_token_mapper()
+            .company_metadata_map
+            .values()
+            .collect();
+        company_metadata_list.sort_by(|a, b| a.ticker_symbol.cmp(&b.ticker_symbol));
+
+        let mut company_names: Vec<(&TickerSymbol, &str)> = Vec::new();
+        for company_metadata in company_metadata_list {
+            let mut seen_company_names = HashSet::new();
+
+            for company_name in company_metadata
+                .company_name
+                .iter()
+                .chain(&company_metadata.alternate_company_names)
+            {
+                if seen_company_names.insert(company_name) {
+                    company_names.push((&company_metadata.ticker_symbol, company_name));
+                }
+            }
+        }
+
+        let results = Self::process_batch(
+            company_names.iter().map(|(_, company_name)| *company_name),
+            |company_name| self.extract(company_name),
+        );
+
+        let mut issues = Vec::new();
+
+        for ((ticker_symbol, company_name), results) in company_names.into_iter().zip(results) {
+            let results = results?;
+            let is_resolved = results.contains_key(ticker_symbol);
+
+            let mut other_ticker_symbols: Vec<TickerSymbol> = results
+                .into_keys()
+                .filter(|extracted_ticker_symbol| extracted_ticker_symbol != ticker_symbol)
+                .collect();
+            other_ticker_symbols.sort();
+
+            let kind = if !is_resolved {
+                CompanyNameConsistencyIssueKind::Unresolved
+            } else if !other_ticker_symbols.is_empty() {
+                CompanyNameConsistencyIssueKind::Ambiguous
+            } else {
+                continue;
+            };
+
+            issues.push(CompanyNameConsistencyIssue {
+                kind,
+                ticker_symbol: ticker_symbol.clone(),
+                company_name: company_name.to_string(),
+                other_ticker_symbols,
+            });
+        }
+
+        Ok(issues)
+    }
+
+    /// Extracts ticker symbols, and their frequencies, from the provided text.
+    ///
+    /// # Arguments
+    /// * `text` - The text document to process.
+    ///
+    /// # Errors
+    /// Returns an error if processing fails.
+    pub fn extract(&self, text: &str) -> Result<TickerSymbolFrequencyMap, Error> {
+        // Skip entirely if there is no text
+        if text.is_empty() {
+            return Ok(TickerSymbolFrequencyMap::new());
+        }
+
+        self.company_token_processor.process_text_doc(text)
+    }
+
+    /// Extracts the individual ticker symbol mentions from the provided text.
+    ///
+    /// # Arguments
+    /// * `text` - The text document to process.
+    ///
+    /// # Errors
+    /// Returns an error if processing fails.
+    pub fn extract_mentions(&self, text: &str) -> Result<Vec<TickerSymbolMention>, Error> {
+        // Skip entirely if there is no text
+        if text.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.company_token_processor.process_text_doc_mentions(text)
+    }
+
+    /// Extracts ticker symbols from the provided text, along with their
+    /// frequencies and confidence scores.
+    ///
+    /// See `CompanyTokenProcessor::process_text_doc_results` for details on how
+    /// confidence scores are determined.
+    ///
+    /// # Arguments
+    /// * `text` - The text document to process.
+    ///
+    /// # Errors
+    /// Returns an error if processing fails.
+    pub fn extract_results(&self, text: &str) -> Result<Vec<TickerSymbolResult>, Error> {
+        // Skip entirely if there is no text
+        if text.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.company_token_processor.process_text_doc_results(text)
+    }
+
+    /// Extracts ticker symbols, and their frequencies, from the visible text of
+    /// an HTML or Markdown document.
+    ///
+    /// See `MarkupText` for details on how the visible text is extracted.
+    ///
+    /// # Arguments
+    /// * `source` - The source document to process.
+    /// * `format` - The markup language of the source document.
+    ///
+    /// # Errors
+    /// Returns an error if processing fails.
+    pub fn extract_markup(
+        &self,
+        source: &str,
+        format: MarkupFormat,
+    ) -> Result<TickerSymbolFrequencyMap, Error> {
+        self.extract(MarkupText::new(source, format).text())
+    }
+
+    /// Extracts the individual ticker symbol mentions from the visible text of
+    /// an HTML or Markdown document.
+    ///
+    /// The byte and character ranges of each mention refer to the source
+    /// document, so that mentions can be highlighted in the original markup.
+    ///
+    /// # Arguments
+    /// * `source` - The source document to process.
+    /// * `format` - The markup language of the source document.
+    ///
+    /// # Errors
+    /// Returns an error if processing fails.
+    ///
+    /// # Example
+    /// ```
+    /// use ticker_sniffer::{MarkupFormat, TickerSniffer, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG};
+    ///
+    /// let ticker_sniffer = TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+    ///
+    /// let html = "<p>Analysts upgraded <a href=\"/aapl\">Apple</a>.</p>";
+    /// let mentions = ticker_sniffer
+    ///     .extract_markup_mentions(html, MarkupFormat::Html)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(mentions[0].ticker_symbol, "AAPL");
+    /// assert_eq!(&html[mentions[0].byte_range.clone()], "Apple");
+    /// ```
+    pub fn extract_markup_mentions(
+        &self,
+        source: &str,
+        format: MarkupFormat,
+    ) -> Result<Vec<TickerSymbolMention>, Error> {
+        let markup_text = MarkupText::new(source, format);
+
+        Ok(self
+            .extract_mentions(markup_text.text())?
+            .into_iter()
+            .map(|mention| markup_text.to_source_mention(mention))
+            .collect())
+    }
+
+    /// Extracts ticker symbols from the visible text of an HTML or Markdown
+    /// document, along with their frequencies and confidence scores.
+    ///
+    /// # Arguments
+    /// * `source` - The source document to process.
+    /// * `format` - The markup language of the source document.
+    ///
+    /// # Errors
+    /// Returns an error if processing fails.
+    pub fn extract_markup_results(
+        &self,
+        source: &str,
+        format: MarkupFormat,
+    ) -> Result<Vec<TickerSymbolResult>, Error> {
+        self.extract_results(MarkupText::new(source, format).text())
+    }
+
+    /// Extracts ticker symbols, and their frequencies, from each of the provided
+    /// text documents.
+    ///
+    /// The symbol index is shared across all documents. With the `rayon` feature
+    /// enabled, documents are processed in parallel. Either way, the results are
+    /// returned in the same order as the input documents, and a failure in one
+    /// document does not affect the others.
+    ///
+    /// # Arguments
+    /// * `texts` - The text documents to process.
+    ///
+    /// # Returns
+    /// * A result for each text document, in input order.
+    ///
+    /// # Example
+    /// ```
+    /// use ticker_sniffer::{TickerSniffer, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG};
+    ///
+    /// let ticker_sniffer = TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+    ///
+    /// let results = ticker_sniffer.extract_batch(["Apple rose", "Microsoft fell"]);
+    /// assert_eq!(results[0].as_ref().unwrap().get("AAPL"), Some(&1));
+    /// assert_eq!(results[1].as_ref().unwrap().get("MSFT"), Some(&1));
+    /// ```
+    pub fn extract_batch<I>(&self, texts: I) -> Vec<Result<TickerSymbolFrequencyMap, Error>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str> + Send,
+    {
+        Self::process_batch(texts, |text| self.extract(text))
+    }
+
+    /// Extracts ticker symbols, along with their frequencies and confidence
+    /// scores, from each of the provided text documents.
+    ///
+    /// See `extract_batch` for details on how documents are processed.
+    ///
+    /// # Arguments
+    /// * `texts` - The text documents to process.
+    ///
+    /// # Returns
+    /// * A result for each text document, in input order.
+    pub fn extract_results_batch<I>(&self, texts: I) -> Vec<Result<Vec<TickerSymbolResult>, Error>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str> + Send,
+    {
+        Self::process_batch(texts, |text| self.extract_results(text))
+    }
+
+    /// Applies a processing function to each of the provided text documents,
+    /// in parallel if the `rayon` feature is enabled.
+    ///
+    /// # Arguments
+    /// * `texts` - The text documents to process.
+    /// * `process` - The function to apply to each text document.
+    ///
+    /// # Returns
+    /// * The output for each text document, in input order.
+    fn process_batch<I, T, F>(texts: I, process: F) -> Vec<T>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str> + Send,
+        T: Send,
+        F: Fn(&str) -> T + Sync,
+    {
+        let texts: Vec<I::Item> = texts.into_iter().collect();
+
+        #[cfg(feature = "rayon")]
+        {
+            texts
+                .into_par_iter()
+                .map(|text| process(text.as_ref()))
+                .collect()
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        {
+            texts
+                .into_iter()
+                .map(|text| process(text.as_ref()))
+                .collect()
+        }
+    }
+
+    /// Processes the provided text while recording the decisions made at each
+    /// stage of the token matching pipeline.
+    ///
+    /// This is intended for diagnosing missing or spurious ticker symbols, and is
+    /// slower than `extract`.
+    ///
+    /// # Arguments
+    /// * `text` - The text document to process.
+    ///
+    /// # Errors
+    /// Returns an error if processing fails.
+    ///
+    /// # Example
+    /// ```
+    /// use ticker_sniffer::{TickerSniffer, TickerSymbolTraceOutcome, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG};
+    ///
+    /// let ticker_sniffer = TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+    ///
+    /// let trace = ticker_sniffer.explain("Apple, Microsoft and AMZN").unwrap();
+    /// let amzn_trace = trace
+    ///     .ticker_symbol_traces
+    ///     .iter()
+    ///     .find(|ticker_symbol_trace| ticker_symbol_trace.ticker_symbol == "AMZN")
+    ///     .unwrap();
+    ///
+    /// assert!(trace.ratio_exact_matches < trace.threshold_ratio_exact_matches);
+    /// assert_eq!(amzn_trace.outcome, TickerSymbolTraceOutcome::DroppedByExactMatchRatio);
+    /// ```
+    pub fn explain(&self, text: &str) -> Result<ExtractionTrace, Error> {
+        self.company_token_processor.trace_text_doc(text)
+    }
+}
@@ -0,0 +1,52 @@
+use crate::types::TickerSymbol;
+use std::ops::Range;
+
+/// Describes how a ticker symbol mention was matched within a text document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TickerSymbolMentionKind {
+    /// The mention was matched against a company name or one of its aliases.
+    CompanyName,
+
+    /// The mention was matched against the bare ticker symbol itself.
+    TickerSymbol,
+
+    /// The mention was matched against a cashtag (e.g., "$AAPL"), which is kept
+    /// regardless of the exact match ratio. The mention's range excludes the
+    /// dollar sign.
+    Cashtag,
+
+    /// The mention was matched against a ticker symbol prefixed by the name of
+    /// its exchange (e.g., "NASDAQ: AAPL"), which is kept regardless of the exact
+    /// match ratio. The mention's range includes the exchange name.
+    ExchangePrefixed,
+}
+
+/// Represents a single mention of a ticker symbol within a text document.
+///
+/// Unlike a `TickerSymbolFrequencyMap`, which only tracks how many times a
+/// ticker symbol was referenced, a mention retains where the reference was
+/// located in the original text, so that it can be highlighted or linked.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TickerSymbolMention {
+    /// The ticker symbol the mention resolves to.
+    pub ticker_symbol: TickerSymbol,
+
+    /// The byte range of the mention in the original text.
+    pub byte_range: Range<usize>,
+
+    /// The character range of the mention in the original text.
+    pub char_range: Range<usize>,
+
+    /// The text of the mention, as it appears in the original text (or, for
+    /// mentions extracted from markup, as it appears in its visible text).
+    pub text: String,
+
+    /// How the mention was matched.
+    pub kind: TickerSymbolMentionKind,
+
+    /// A normalized confidence, between `0.0` and `1.0`, that the mention
+    /// actually refers to the ticker symbol.
+    pub confidence: f32,
+}
@@ -0,0 +1,35 @@
+use crate::types::{TickerSymbol, TickerSymbolFrequency};
+use crate::{AssetType, Market};
+
+/// Represents a ticker symbol extracted from a text document, along with the
+/// evidence supporting it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TickerSymbolResult {
+    /// The extracted ticker symbol.
+    pub ticker_symbol: TickerSymbol,
+
+    /// The number of times the ticker symbol was referenced, as reported in a
+    /// `TickerSymbolFrequencyMap`.
+    pub frequency: TickerSymbolFrequency,
+
+    /// The number of individual mentions supporting the ticker symbol.
+    pub mention_count: usize,
+
+    /// A normalized confidence, between `0.0` and `1.0`, that the ticker symbol
+    /// is actually referenced in the text document.
+    ///
+    /// The confidence of each mention is combined so that every additional
+    /// mention increases the confidence, without it ever exceeding `1.0`.
+    pub confidence: f32,
+
+    /// The class of security the ticker symbol represents, if known.
+    pub asset_type: Option<AssetType>,
+
+    /// The market the ticker symbol is listed on.
+    pub market: Market,
+
+    /// The current ticker symbol of the company, if the extracted ticker symbol
+    /// has since been replaced (e.g., "META" for "FB").
+    pub successor_ticker_symbol: Option<TickerSymbol>,
+}
@@ -32,6 +32,27 @@ impl TokenMapper {
         }
     }
 
+    /// Creates a new instance of `TokenMapper` from tokens which have already been
+    /// assigned IDs, where each token's ID is its position in the vector.
+    ///
+    /// # Arguments
+    /// * `tokens` - The token character vectors, ordered by ID.
+    pub(crate) fn with_tokens(tokens: Vec<TokenVector>) -> Self {
+        let mut token_map = HashMap::with_capacity(tokens.len());
+        let mut reverse_token_map = HashMap::with_capacity(tokens.len());
+
+        for (id, token_vector) in tokens.into_iter().enumerate() {
+            token_map.insert(token_vector.clone(), id);
+            reverse_token_map.insert(id, token_vector);
+        }
+
+        TokenMapper {
+            next_id: token_map.len(),
+            token_map,
+            reverse_token_map,
+        }
+    }
+
     /// Adds a token to the map if it doesn't already exist, and returns its unique ID.
     ///
     /// If the token is already present in the `token_map`, its existing ID is returned.
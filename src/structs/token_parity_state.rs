@@ -8,6 +8,7 @@ use std::collections::HashMap;
 /// This struct is used to track the relationship between tokens from the input query text
 /// and corresponding tokens in a company's token sequences for a specific ticker symbol.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TokenParityState {
     /// The unique token ID associated with a ticker symbol.
     pub ticker_symbol_token_id: TickerSymbolTokenId,
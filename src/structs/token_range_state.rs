@@ -13,6 +13,7 @@ use crate::{CompanyTokenMapper, Error, TokenParityState};
 /// This struct tracks information about tokens in a document and their relation
 /// to a company's ticker symbol and token sequences.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TokenRangeState {
     /// The ticker symbol associated with this token range.
     pub ticker_symbol: TickerSymbol,
@@ -169,6 +170,34 @@ impl TokenRangeState {
         }
     }
 
+    /// Calculates a normalized confidence that the range refers to its ticker symbol.
+    ///
+    /// The confidence is the company token coverage, scaled by the range score so
+    /// that longer contiguous matches are favored over single-word matches. Ranges
+    /// which matched directly on the ticker symbol are at least as confident as a
+    /// bare ticker symbol mention.
+    ///
+    /// # Arguments
+    /// * `ticker_symbol_mention_confidence` - The confidence of a bare ticker symbol mention.
+    ///
+    /// # Returns
+    /// * The confidence, between `0.0` and `1.0`.
+    pub fn calc_confidence(&self, ticker_symbol_mention_confidence: f32) -> f32 {
+        let range_score = self
+            .range_score
+            .unwrap_or(self.company_token_coverage)
+            .max(0.0);
+
+        let confidence = self.company_token_coverage * range_score / (range_score + 1.0);
+
+        if self.is_matched_on_ticker_symbol == Some(true) {
+            confidence.max(ticker_symbol_mention_confidence)
+        } else {
+            confidence
+        }
+        .clamp(0.0, 1.0)
+    }
+
     /// Counts the frequencies of ticker symbols based on unique query token indices.
     ///
     /// # Arguments
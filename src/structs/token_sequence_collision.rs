@@ -0,0 +1,68 @@
+use crate::types::{TickerSymbol, Token};
+
+/// A ticker symbol which can be matched by the token sequence of a
+/// `TokenSequenceCollision`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenSequenceCollisionMatch {
+    /// The ticker symbol.
+    pub ticker_symbol: TickerSymbol,
+
+    /// The tokens of the company name, or alternate name, which starts with the
+    /// colliding token sequence.
+    pub company_tokens: Vec<Token>,
+
+    /// The fraction of the company name tokens covered by the colliding token
+    /// sequence, between `0.0` and `1.0`.
+    pub company_token_coverage: f32,
+}
+
+/// A token sequence which can be matched to more than one ticker symbol, such
+/// as a company name shared by several funds.
+///
+/// Collisions are collected with `CompanyTokenMapper::collect_token_sequence_collisions`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenSequenceCollision {
+    /// The colliding token sequence.
+    pub tokens: Vec<Token>,
+
+    /// The ticker symbols which can be matched by the token sequence, in order of
+    /// descending coverage, then by ticker symbol.
+    pub matches: Vec<TokenSequenceCollisionMatch>,
+
+    /// An estimate of how likely the token sequence is to cause false positives.
+    ///
+    /// This is the total coverage of every match except the best one, divided by
+    /// the number of tokens, so that short sequences which fully match many
+    /// ticker symbols rank highest.
+    pub ambiguity_score: f32,
+}
+
+impl TokenSequenceCollision {
+    /// The header of the tab-separated values written by `to_tsv_row`.
+    pub const TSV_HEADER: &'static str = "ambiguity_score\ttokens\tticker_symbols";
+
+    /// Formats the collision as a row of tab-separated values, with the columns of
+    /// `TSV_HEADER`. Ticker symbols are listed with their coverage (e.g.,
+    /// "FBGRX:1.00,FBCG:0.75").
+    pub fn to_tsv_row(&self) -> String {
+        let ticker_symbols: Vec<String> = self
+            .matches
+            .iter()
+            .map(|collision_match| {
+                format!(
+                    "{}:{:.2}",
+                    collision_match.ticker_symbol, collision_match.company_token_coverage
+                )
+            })
+            .collect();
+
+        format!(
+            "{:.4}\t{}\t{}",
+            self.ambiguity_score,
+            self.tokens.join(" "),
+            ticker_symbols.join(",")
+        )
+    }
+}
@@ -0,0 +1,24 @@
+use crate::types::Token;
+use std::ops::Range;
+
+/// Represents a token along with the location it was sourced from in the
+/// original text.
+///
+/// Tokens are normalized (e.g., uppercased, with possessive endings and
+/// punctuation removed), so the `surface` field retains the text exactly as
+/// it appears in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenSpan {
+    /// The normalized token.
+    pub token: Token,
+
+    /// The byte range of the token in the original text.
+    pub byte_range: Range<usize>,
+
+    /// The character range of the token in the original text.
+    pub char_range: Range<usize>,
+
+    /// The raw text the token was derived from.
+    pub surface: String,
+}
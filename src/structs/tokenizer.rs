@@ -1,7 +1,37 @@
-use crate::constants::STOP_WORDS;
-use crate::types::{Token, TokenCharCode, TokenRef, TokenVector};
+use crate::constants::MIN_TICKER_SYMBOL_UPPERCASE_RATIO;
+use crate::types::{TickerSymbol, Token, TokenCharCode, TokenRef, TokenVector};
+use crate::utils::normalize_ticker_symbol;
+use crate::utils::normalize_ticker_symbol::{
+    normalize_share_class_ticker_symbol, SHARE_CLASS_SEPARATORS,
+};
+use crate::{Market, StopWords, TokenSpan};
 use std::char;
 use std::collections::HashSet;
+use std::ops::Range;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// The maximum number of words preceding the word with the colon which can be
+/// part of an exchange name (e.g., "NASDAQ Global Select:").
+const MAX_EXCHANGE_PREFIX_PRECEDING_WORDS: usize = 2;
+
+/// A word of offset-tagged characters, paired with the byte offset its span
+/// starts at, if it is not the offset of its first character.
+type OffsetWord = (Vec<(char, usize)>, Option<usize>);
+
+/// Determines which words of a text are tokenized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WordFilter {
+    /// Every word is tokenized.
+    All,
+
+    /// Only cashtags (e.g., "$AAPL") are tokenized, without their dollar sign.
+    Cashtags,
+
+    /// Only ticker symbols prefixed with an exchange name (e.g., "NASDAQ: AAPL")
+    /// are tokenized.
+    ExchangePrefixed,
+}
 
 /// A utility struct for tokenizing text, with configurable options for
 /// processing text documents, ticker symbols, and verbatim parsing.
@@ -9,6 +39,9 @@ pub struct Tokenizer {
     /// Whether to process text verbatim without normalization or filtering.
     as_verbatim: bool,
 
+    /// Which words of the text are tokenized.
+    word_filter: WordFilter,
+
     /// The minimum ratio of uppercase letters required in a token, if applicable.
     min_uppercase_ratio: Option<f32>,
 
@@ -18,8 +51,24 @@ pub struct Tokenizer {
     /// could improve handling of certain input fields.
     is_case_sensitive: bool,
 
-    /// Preprocessed stop words for filtering tokens.
+    /// The stop words which are filtered from the tokens, if any.
+    stop_words: Option<StopWords>,
+
+    /// The stop words, normalized and uppercased the same way as the tokens they
+    /// are compared against.
     pre_processed_stop_words: Option<HashSet<String>>,
+
+    /// Whether diacritics are removed from letters (e.g., "Nestlé" is tokenized
+    /// as "NESTLE").
+    fold_diacritics: bool,
+
+    /// The suffixes of market-qualified ticker symbols (e.g., the "L" in "VOD.L")
+    /// which are kept intact, rather than being merged into the preceding word.
+    market_suffixes: HashSet<&'static str>,
+
+    /// The normalized share class ticker symbols (e.g., "BRK-B") which are kept
+    /// as single tokens, regardless of the separator used in the text.
+    share_class_ticker_symbols: HashSet<TickerSymbol>,
 }
 
 impl Tokenizer {
@@ -29,22 +78,178 @@ impl Tokenizer {
     pub fn ticker_symbol_parser(is_case_sensitive: bool) -> Self {
         Self {
             as_verbatim: false,
-            min_uppercase_ratio: Some(0.9),
+            word_filter: WordFilter::All,
+            min_uppercase_ratio: Some(MIN_TICKER_SYMBOL_UPPERCASE_RATIO),
             is_case_sensitive,
+            stop_words: None,
             pre_processed_stop_words: None,
+            fold_diacritics: false,
+            market_suffixes: HashSet::new(),
+            share_class_ticker_symbols: HashSet::new(),
         }
     }
 
+    /// Sets the minimum ratio of uppercase letters required in a token.
+    ///
+    /// This only applies when the tokenizer is case sensitive.
+    ///
+    /// # Arguments
+    /// * `min_uppercase_ratio` - The minimum ratio, or `None` to disable the filter.
+    pub fn with_min_uppercase_ratio(mut self, min_uppercase_ratio: Option<f32>) -> Self {
+        self.min_uppercase_ratio = min_uppercase_ratio;
+        self
+    }
+
+    /// Sets the markets whose qualified ticker symbols (e.g., "VOD.L") are kept
+    /// as single tokens, including the period.
+    ///
+    /// Markets without a suffix (i.e., `Market::Us`) are ignored.
+    ///
+    /// # Arguments
+    /// * `markets` - The markets to recognize.
+    pub fn with_markets<'a, I: IntoIterator<Item = &'a Market>>(mut self, markets: I) -> Self {
+        self.market_suffixes = markets
+            .into_iter()
+            .filter_map(|market| market.suffix())
+            .collect();
+        self
+    }
+
+    /// Sets the stop words which are filtered from the tokens.
+    ///
+    /// Company names and the text documents they are matched against must be
+    /// tokenized with the same stop words, or their tokens will not align.
+    ///
+    /// # Arguments
+    /// * `stop_words` - A reference to the stop words to filter.
+    pub fn with_stop_words(mut self, stop_words: &StopWords) -> Self {
+        self.stop_words = Some(stop_words.clone());
+        self.pre_processed_stop_words = self.preprocess_stop_words();
+        self
+    }
+
+    /// Sets whether diacritics are removed from letters, so that accented and
+    /// unaccented spellings produce the same token (e.g., "Nestlé" and "Nestle"
+    /// are both tokenized as "NESTLE").
+    ///
+    /// # Arguments
+    /// * `fold_diacritics` - Whether to remove diacritics.
+    pub fn with_diacritic_folding(mut self, fold_diacritics: bool) -> Self {
+        self.fold_diacritics = fold_diacritics;
+        self.pre_processed_stop_words = self.preprocess_stop_words();
+        self
+    }
+
+    /// Normalizes and uppercases the stop words the same way as tokens, so that
+    /// accented stop words (e.g., "für") are still filtered when diacritics are
+    /// folded.
+    ///
+    /// # Returns
+    /// * The preprocessed stop words, or `None` if stop words are not filtered.
+    fn preprocess_stop_words(&self) -> Option<HashSet<String>> {
+        let stop_words = self.stop_words.as_ref()?;
+
+        Some(
+            stop_words
+                .to_uppercase_set()
+                .iter()
+                .map(|word| {
+                    let chars: Vec<(char, usize)> =
+                        word.char_indices().map(|(idx, c)| (c, idx)).collect();
+
+                    self.normalize_chars(&chars)
+                        .into_iter()
+                        .map(|(c, _)| c)
+                        .collect::<String>()
+                        .to_uppercase()
+                })
+                .collect(),
+        )
+    }
+
+    /// Sets the share class ticker symbols which are recognized in any of their
+    /// common formats (e.g., "BRK.B", "BRK/B", "BRK-B", or "BRK B"), and are
+    /// tokenized to their normalized form (e.g., "BRK-B").
+    ///
+    /// Ticker symbols are normalized with `normalize_ticker_symbol`, and those
+    /// without a share class are ignored.
+    ///
+    /// # Arguments
+    /// * `ticker_symbols` - The ticker symbols to recognize.
+    pub fn with_share_class_ticker_symbols<I, S>(mut self, ticker_symbols: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.share_class_ticker_symbols = ticker_symbols
+            .into_iter()
+            .map(|ticker_symbol| normalize_ticker_symbol(ticker_symbol.as_ref()))
+            .filter(|ticker_symbol| ticker_symbol.contains('-') && !ticker_symbol.contains('.'))
+            .collect();
+        self
+    }
+
     /// Creates a tokenizer configured for parsing arbitrary text documents.
     ///
     /// Normalizes text, filters stop words, and allows tokens with mixed case.
+    /// English stop words are filtered, unless replaced with `with_stop_words`.
     pub fn text_doc_parser(is_case_sensitive: bool) -> Self {
         Self {
             as_verbatim: false,
+            word_filter: WordFilter::All,
+            min_uppercase_ratio: None,
+            is_case_sensitive,
+            stop_words: None,
+            pre_processed_stop_words: None,
+            fold_diacritics: false,
+            market_suffixes: HashSet::new(),
+            share_class_ticker_symbols: HashSet::new(),
+        }
+        .with_stop_words(&StopWords::default())
+    }
+
+    /// Creates a tokenizer configured for parsing cashtags (e.g., "$AAPL").
+    ///
+    /// Only words which start with a dollar sign followed by a letter are kept,
+    /// so dollar amounts (e.g., "$100" or "$3.28") are ignored. Cashtags are
+    /// explicit references to ticker symbols, so they are not filtered by case,
+    /// and the dollar sign is excluded from the token and its byte range.
+    pub fn cashtag_parser() -> Self {
+        Self {
+            as_verbatim: false,
+            word_filter: WordFilter::Cashtags,
             min_uppercase_ratio: None,
+            is_case_sensitive: false,
+            stop_words: None,
+            pre_processed_stop_words: None,
+            fold_diacritics: false,
+            market_suffixes: HashSet::new(),
+            share_class_ticker_symbols: HashSet::new(),
+        }
+    }
+
+    /// Creates a tokenizer configured for parsing ticker symbols prefixed with an
+    /// exchange name, as is typical of press releases (e.g., "(NASDAQ: AAPL)",
+    /// "NYSE:WMT", or "(NYSE American: XYZ)").
+    ///
+    /// Exchange names are recognized with `Market::from_exchange_prefix`, and ticker
+    /// symbols prefixed with the exchange of a market other than `Market::Us` are
+    /// qualified with the market's suffix (e.g., "TSX: SHOP" is tokenized as
+    /// "SHOP.TO"). Like `ticker_symbol_parser`, the ticker symbol must meet the
+    /// uppercase ratio when case sensitive, but the exchange name is matched
+    /// regardless of case (e.g., "Nasdaq: MSFT"), and is included in the byte
+    /// range of the token.
+    pub fn exchange_prefixed_parser(is_case_sensitive: bool) -> Self {
+        Self {
+            as_verbatim: false,
+            word_filter: WordFilter::ExchangePrefixed,
+            min_uppercase_ratio: Some(MIN_TICKER_SYMBOL_UPPERCASE_RATIO),
             is_case_sensitive,
-            pre_processed_stop_words: Some(Self::preprocess_stop_words()),
-            // TODO: Make configurable
+            stop_words: None,
+            pre_processed_stop_words: None,
+            fold_diacritics: false,
+            market_suffixes: HashSet::new(),
+            share_class_ticker_symbols: HashSet::new(),
         }
     }
 
@@ -54,9 +259,14 @@ impl Tokenizer {
     pub fn verbatim_doc_parser() -> Self {
         Self {
             as_verbatim: true,
+            word_filter: WordFilter::All,
             min_uppercase_ratio: None,
             is_case_sensitive: false,
+            stop_words: None,
             pre_processed_stop_words: None,
+            fold_diacritics: false,
+            market_suffixes: HashSet::new(),
+            share_class_ticker_symbols: HashSet::new(),
         }
     }
 
@@ -68,81 +278,625 @@ impl Tokenizer {
     /// # Returns
     /// * A vector of tokens as strings.
     pub fn tokenize(&self, text: &str) -> Vec<Token> {
+        self.tokenize_with_byte_ranges(text)
+            .into_iter()
+            .map(|(token, _)| token)
+            .collect()
+    }
+
+    /// Splits the input text into tokens, keeping track of where each token
+    /// was sourced from in the original text.
+    ///
+    /// Tokens are normalized exactly as they are with `tokenize`, but each is
+    /// returned with its byte range, character range, and surface form. The
+    /// ranges span from the first to the last retained character of the token,
+    /// so surrounding punctuation and possessive endings are not included
+    /// (except that the ranges of exchange-prefixed ticker symbols start at the
+    /// exchange name).
+    ///
+    /// # Arguments
+    /// * `text` - The input text to tokenize.
+    ///
+    /// # Returns
+    /// * A vector of token spans, ordered by their position in the text.
+    pub fn tokenize_with_spans(&self, text: &str) -> Vec<TokenSpan> {
+        let tokens = self.tokenize_with_byte_ranges(text);
+
+        // Tokens are mostly ordered by position, so the character offsets are
+        // counted incrementally from the previous offset, in either direction
+        let mut char_offset = 0;
+        let mut byte_offset = 0;
+        let mut to_char_offset = |target_byte_offset: usize| {
+            if target_byte_offset >= byte_offset {
+                char_offset += text[byte_offset..target_byte_offset].chars().count();
+            } else {
+                char_offset -= text[target_byte_offset..byte_offset].chars().count();
+            }
+            byte_offset = target_byte_offset;
+            char_offset
+        };
+
+        tokens
+            .into_iter()
+            .map(|(token, byte_range)| {
+                let char_range = to_char_offset(byte_range.start)..to_char_offset(byte_range.end);
+
+                TokenSpan {
+                    token,
+                    surface: text[byte_range.clone()].to_string(),
+                    byte_range,
+                    char_range,
+                }
+            })
+            .collect()
+    }
+
+    /// Splits the input text into tokens, paired with the byte ranges they were
+    /// sourced from.
+    ///
+    /// # Arguments
+    /// * `text` - The input text to tokenize.
+    ///
+    /// # Returns
+    /// * A vector of tokens paired with their byte ranges in `text`.
+    fn tokenize_with_byte_ranges(&self, text: &str) -> Vec<(Token, Range<usize>)> {
+        // Each character is paired with its byte offset in the original text
+        let chars: Vec<(char, usize)> = text.char_indices().map(|(idx, c)| (c, idx)).collect();
+
         if self.as_verbatim {
-            return text
-                .split_whitespace() // Split into words
-                .map(|word| word.to_string()) // Convert each word into a Token object
+            return Self::split_whitespace_with_offsets(&chars)
+                .into_iter()
+                .filter_map(|word| {
+                    let byte_range = Self::get_byte_range(text, &word)?;
+                    Some((word.iter().map(|(c, _)| c).collect(), byte_range))
+                })
                 .collect();
         }
 
         let stop_words = self.pre_processed_stop_words.as_ref();
 
-        // Preprocess and tokenize the text
-        text.replace("-\n", "") // Merge hyphenated words across lines
-            .replace(['\n', '\r'], " ") // Normalize line breaks to spaces
-            .replace('\r', " ") // Handle potential carriage returns
-            .replace("--", " ") // Replace standalone double hyphens
-            .replace(',', " ") // Normalize commas to spaces
-            .split_whitespace() // Split into words
-            // Remove possessive endings
-            .map(|word| {
-                let stripped = word.replace("'s", "").replace("s'", "");
+        // Normalize Unicode spellings, which plain ASCII text never needs
+        let chars = if text.is_ascii() {
+            chars
+        } else {
+            self.normalize_chars(&chars)
+        };
 
-                stripped
-                    .chars()
-                    .filter(|c| c.is_alphanumeric())
-                    .collect::<String>()
-            })
-            .filter(|word| {
-                if !self.is_case_sensitive {
-                    true
-                } else {
-                    // Apply uppercase ratio filter and any capital letter requirement
-                    let passes_uppercase_ratio = self.min_uppercase_ratio.is_none_or(|ratio| self.calc_uppercase_ratio(word) >= ratio);
+        // Preprocess the text
+        let chars = Self::remove_sequence(&chars, &['-', '\n']); // Merge hyphenated words across lines
+        let chars = Self::replace_sequence(&chars, &['\n'], ' '); // Normalize line breaks to spaces
+        let chars = Self::replace_sequence(&chars, &['\r'], ' '); // Handle potential carriage returns
+        let chars = Self::replace_sequence(&chars, &['-', '-'], ' '); // Replace standalone double hyphens
+        let chars = Self::replace_sequence(&chars, &[','], ' '); // Normalize commas to spaces
+
+        let words = self.merge_share_class_words(Self::split_whitespace_with_offsets(&chars));
 
-                    let passes_any_caps_or_is_number = word.chars().any(|c| c.is_uppercase())
-                        || word.chars().all(|c| c.is_numeric());
+        // Keep only the configured words
+        let words: Vec<OffsetWord> = match self.word_filter {
+            WordFilter::All => words.into_iter().map(|word| (word, None)).collect(),
+            WordFilter::Cashtags => words
+                .iter()
+                .filter_map(|word| Self::strip_cashtag_prefix(word))
+                .map(|word| (word, None))
+                .collect(),
+            WordFilter::ExchangePrefixed => Self::select_exchange_prefixed_words(&words)
+                .into_iter()
+                .map(|(word, exchange_start_offset)| (word, Some(exchange_start_offset)))
+                .collect(),
+        };
+
+        // Tokenize the text
+        words
+            .into_iter()
+            // Remove possessive endings
+            .map(|(word, start_offset)| {
+                let stripped = Self::remove_sequence(&word, &['\'', 's']);
+                let stripped = Self::remove_sequence(&stripped, &['s', '\'']);
 
-                    passes_uppercase_ratio && passes_any_caps_or_is_number
+                // Replace share class ticker symbols with their normalized form
+                if let Some(share_class_word) = self.normalize_share_class_word(&stripped) {
+                    return (share_class_word, start_offset);
                 }
-            })
-            // Split hyphenated words into multiple words
-            .flat_map(|word| {
-                let parts: Vec<String> = word
-                    .split('-')
-                    .map(|part| {
-                        part.chars()
-                            .filter(|c| c.is_alphanumeric())
-                            .collect::<String>()
+
+                // Retain the period of a market-qualified ticker symbol
+                let market_suffix_period_idx = self.find_market_suffix_period(&stripped);
+
+                // Note: Hyphens are filtered out here as well, so hyphenated words
+                // are merged into a single word
+                let word = stripped
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(idx, (c, _))| {
+                        c.is_alphanumeric() || Some(*idx) == market_suffix_period_idx
                     })
-                    .collect();
+                    .map(|(_, c)| c)
+                    .collect::<Vec<(char, usize)>>();
 
-                if parts.len() > 1 {
-                    parts.into_iter() // Only split into parts if there are multiple segments
-                } else {
-                    vec![word.replace('-', "")].into_iter() // Otherwise, use the whole word
-                }
+                (word, start_offset)
             })
-            // Filter to alphanumeric and uppercase
-            .map(|word| {
-                word.chars()
-                    .filter(|c| c.is_alphanumeric())
-                    .collect::<String>() // Collect filtered characters into a String
-                    .to_uppercase() // Convert to uppercase
+            .filter_map(|(word, start_offset)| {
+                let mut byte_range = Self::get_byte_range(text, &word)?;
+                if let Some(start_offset) = start_offset {
+                    byte_range.start = start_offset;
+                }
+                let word: String = word.iter().map(|(c, _)| c).collect();
+
+                Some((word, byte_range))
             })
-            // Skip empty words and stop words
-            .filter(|word| !word.is_empty() && stop_words.is_none_or(|sw| !sw.contains(word)))
+            .filter(|(word, _)| self.passes_case_filter(word))
+            // Convert to uppercase
+            .map(|(word, byte_range)| (word.to_uppercase(), byte_range))
+            // Skip stop words
+            .filter(|(word, _)| stop_words.is_none_or(|sw| !sw.contains(word)))
             .collect()
     }
 
+    /// Determines whether a word passes the uppercase ratio filter and any capital
+    /// letter requirement, when the tokenizer is case sensitive.
+    ///
+    /// # Arguments
+    /// * `word` - A reference to the word to check.
+    fn passes_case_filter(&self, word: &TokenRef) -> bool {
+        if !self.is_case_sensitive {
+            return true;
+        }
+
+        let passes_uppercase_ratio = self
+            .min_uppercase_ratio
+            .is_none_or(|ratio| self.calc_uppercase_ratio(word) >= ratio);
+
+        let passes_any_caps_or_is_number =
+            word.chars().any(|c| c.is_uppercase()) || word.chars().all(|c| c.is_numeric());
+
+        passes_uppercase_ratio && passes_any_caps_or_is_number
+    }
+
+    /// Strips the dollar sign from a cashtag (e.g., "$AAPL"), ignoring any
+    /// preceding punctuation (e.g., "($AAPL)").
+    ///
+    /// # Arguments
+    /// * `word` - A slice of characters paired with their original byte offsets.
+    ///
+    /// # Returns
+    /// * The characters following the dollar sign, or `None` if the word is not a
+    ///   cashtag (e.g., "$100" or "US$5").
+    fn strip_cashtag_prefix(word: &[(char, usize)]) -> Option<Vec<(char, usize)>> {
+        let start = word.iter().position(|(c, _)| c.is_alphanumeric())?;
+
+        let is_cashtag = start > 0 && word[start - 1].0 == '$' && word[start].0.is_alphabetic();
+
+        is_cashtag.then(|| word[start..].to_vec())
+    }
+
+    /// Selects the ticker symbols which follow an exchange name and a colon (e.g.,
+    /// "AAPL" in "(NASDAQ: AAPL)" or "WMT" in "NYSE:WMT").
+    ///
+    /// # Arguments
+    /// * `words` - The words, each being a vector of offset-tagged characters.
+    ///
+    /// # Returns
+    /// * The ticker symbol words, qualified with the suffix of the exchange's
+    ///   market, if any, each paired with the byte offset of its exchange name.
+    fn select_exchange_prefixed_words(
+        words: &[Vec<(char, usize)>],
+    ) -> Vec<(Vec<(char, usize)>, usize)> {
+        let mut selected_words = Vec::new();
+
+        for (word_idx, word) in words.iter().enumerate() {
+            let Some(colon_idx) = word.iter().position(|(c, _)| *c == ':') else {
+                continue;
+            };
+
+            // The exchange name may span multiple words (e.g., "NYSE American:"),
+            // so the longest name ending at the colon is preferred
+            let Some((market, exchange_start_offset)) = (0..=MAX_EXCHANGE_PREFIX_PRECEDING_WORDS)
+                .rev()
+                .filter(|preceding_word_count| *preceding_word_count <= word_idx)
+                .find_map(|preceding_word_count| {
+                    let exchange_chars: Vec<(char, usize)> = words
+                        [word_idx - preceding_word_count..word_idx]
+                        .iter()
+                        .flat_map(|preceding_word| preceding_word.iter().copied().chain([(' ', 0)]))
+                        .chain(word[..colon_idx].iter().copied())
+                        .skip_while(|(c, _)| !c.is_alphanumeric())
+                        .collect();
+
+                    let (_, exchange_start_offset) = *exchange_chars.first()?;
+                    let exchange_prefix: String = exchange_chars.iter().map(|(c, _)| c).collect();
+
+                    Market::from_exchange_prefix(&exchange_prefix)
+                        .map(|market| (market, exchange_start_offset))
+                })
+            else {
+                continue;
+            };
+
+            // The ticker symbol either directly follows the colon, or is the next word
+            let ticker_symbol_word = if colon_idx + 1 < word.len() {
+                &word[colon_idx + 1..]
+            } else {
+                match words.get(word_idx + 1) {
+                    Some(next_word) => next_word.as_slice(),
+                    None => continue,
+                }
+            };
+
+            let Some(start) = ticker_symbol_word
+                .iter()
+                .position(|(c, _)| c.is_alphanumeric())
+            else {
+                continue;
+            };
+            let Some(end) = ticker_symbol_word
+                .iter()
+                .rposition(|(c, _)| c.is_alphanumeric())
+                .map(|idx| idx + 1)
+            else {
+                continue;
+            };
+
+            let mut selected_word = ticker_symbol_word[start..end].to_vec();
+            let ticker_symbol: String = selected_word.iter().map(|(c, _)| c).collect();
+
+            // A repeated exchange prefix (e.g., "NYSE: NYSE: WMT") is not a ticker
+            // symbol, and is instead selected as the exchange of the next word
+            if ticker_symbol_word.iter().any(|(c, _)| *c == ':')
+                || Market::from_exchange_prefix(&ticker_symbol).is_some()
+            {
+                continue;
+            }
+
+            // Qualify the ticker symbol with the market's suffix (e.g., "SHOP.TO"),
+            // keeping the end offset of the original word
+            let qualified_ticker_symbol = market.qualify_ticker_symbol(&ticker_symbol);
+            if qualified_ticker_symbol != ticker_symbol {
+                let (_, last_offset) = selected_word[selected_word.len() - 1];
+
+                selected_word.extend(
+                    qualified_ticker_symbol[ticker_symbol.len()..]
+                        .chars()
+                        .map(|c| (c, last_offset)),
+                );
+            }
+
+            selected_words.push((selected_word, exchange_start_offset));
+        }
+
+        selected_words
+    }
+
+    /// Merges adjacent words which form a share class ticker symbol when separated
+    /// by whitespace (e.g., "BRK B").
+    ///
+    /// # Arguments
+    /// * `words` - The words, each being a vector of offset-tagged characters.
+    ///
+    /// # Returns
+    /// * The words, with share class ticker symbols merged into single words.
+    fn merge_share_class_words(&self, words: Vec<Vec<(char, usize)>>) -> Vec<Vec<(char, usize)>> {
+        if self.share_class_ticker_symbols.is_empty() {
+            return words;
+        }
+
+        let mut merged_words: Vec<Vec<(char, usize)>> = Vec::with_capacity(words.len());
+        let mut words = words.into_iter().peekable();
+
+        while let Some(mut word) = words.next() {
+            if let Some(next_word) = words.peek() {
+                let mut candidate = word.clone();
+                if let Some(&(_, offset)) = word.last() {
+                    candidate.push((' ', offset));
+                }
+                candidate.extend(next_word.iter().copied());
+
+                if self.normalize_share_class_word(&candidate).is_some() {
+                    word = candidate;
+                    words.next();
+                }
+            }
+
+            merged_words.push(word);
+        }
+
+        merged_words
+    }
+
+    /// Normalizes a word which is a known share class ticker symbol in any of its
+    /// common formats (e.g., "BRK.B" or "BRK B"), ignoring surrounding punctuation.
+    ///
+    /// # Arguments
+    /// * `word` - A slice of characters paired with their original byte offsets.
+    ///
+    /// # Returns
+    /// * The normalized ticker symbol (e.g., "BRK-B") as offset-tagged characters
+    ///   spanning the original word, or `None` if the word is not a known share
+    ///   class ticker symbol.
+    fn normalize_share_class_word(&self, word: &[(char, usize)]) -> Option<Vec<(char, usize)>> {
+        if self.share_class_ticker_symbols.is_empty() {
+            return None;
+        }
+
+        let start = word.iter().position(|(c, _)| c.is_alphanumeric())?;
+        let end = word.iter().rposition(|(c, _)| c.is_alphanumeric())? + 1;
+        let core: String = word[start..end].iter().map(|(c, _)| c).collect();
+
+        if !core.contains(SHARE_CLASS_SEPARATORS) && !core.contains('^') {
+            return None;
+        }
+
+        // The case of the original text is checked, as normalization converts it
+        // to uppercase
+        let letters: String = core.chars().filter(|c| c.is_alphanumeric()).collect();
+        if !self.passes_case_filter(&letters) {
+            return None;
+        }
+
+        // Suffixes are normalized as share classes, even if they are also market
+        // suffixes, so that a known share class (e.g., "MKC.V" for "MKC-V") is
+        // preferred over a market-qualified ticker symbol
+        let ticker_symbol = normalize_share_class_ticker_symbol(&core);
+        if !self.share_class_ticker_symbols.contains(&ticker_symbol) {
+            return None;
+        }
+
+        // Only the offsets of the first and last characters determine the byte
+        // range of the token
+        let (_, start_offset) = word[start];
+        let (_, last_offset) = word[end - 1];
+
+        let mut normalized_word: Vec<(char, usize)> =
+            ticker_symbol.chars().map(|c| (c, start_offset)).collect();
+        if let Some((_, offset)) = normalized_word.last_mut() {
+            *offset = last_offset;
+        }
+
+        Some(normalized_word)
+    }
+
+    /// Finds the period which separates a ticker symbol from the suffix of one of
+    /// the configured markets (e.g., "SHOP.TO"), ignoring surrounding punctuation.
+    ///
+    /// # Arguments
+    /// * `word` - A slice of characters paired with their original byte offsets.
+    ///
+    /// # Returns
+    /// * The index of the period within `word`, or `None` if the word is not a
+    ///   market-qualified ticker symbol.
+    fn find_market_suffix_period(&self, word: &[(char, usize)]) -> Option<usize> {
+        if self.market_suffixes.is_empty() {
+            return None;
+        }
+
+        let start = word.iter().position(|(c, _)| c.is_alphanumeric())?;
+        let end = word.iter().rposition(|(c, _)| c.is_alphanumeric())? + 1;
+        let period_idx = start + word[start..end].iter().rposition(|(c, _)| *c == '.')?;
+
+        let base = &word[start..period_idx];
+        let suffix: String = word[period_idx + 1..end]
+            .iter()
+            .map(|(c, _)| c.to_ascii_uppercase())
+            .collect();
+
+        let is_qualified = !base.is_empty()
+            && base.iter().all(|(c, _)| *c != '.')
+            && self.market_suffixes.contains(suffix.as_str());
+
+        is_qualified.then_some(period_idx)
+    }
+
+    /// Splits offset-tagged characters into words, using whitespace as the delimiter.
+    ///
+    /// # Arguments
+    /// * `chars` - A slice of characters paired with their original byte offsets.
+    ///
+    /// # Returns
+    /// * A vector of words, each being a vector of offset-tagged characters.
+    fn split_whitespace_with_offsets(chars: &[(char, usize)]) -> Vec<Vec<(char, usize)>> {
+        chars
+            .split(|(c, _)| c.is_whitespace())
+            .filter(|word| !word.is_empty())
+            .map(|word| word.to_vec())
+            .collect()
+    }
+
+    /// Normalizes offset-tagged characters, so that equivalent spellings of a word
+    /// produce the same token.
+    ///
+    /// Each character, along with any combining marks which follow it, is
+    /// converted to its NFKC form (e.g., full-width "ＡＡＰＬ" to "AAPL", and
+    /// non-breaking spaces to spaces), after which:
+    /// * Typographic apostrophes (e.g., "Intel’s") are unified to "'".
+    /// * Hyphens and en dashes are unified to "-", while em dashes, which separate
+    ///   clauses like a double hyphen, are replaced with a space.
+    /// * Invisible characters (e.g., soft hyphens) are removed.
+    /// * Diacritics are removed (e.g., "Nestlé" to "Nestle"), if configured.
+    ///
+    /// Uppercasing is applied later, and is locale independent.
+    ///
+    /// # Arguments
+    /// * `chars` - A slice of characters paired with their original byte offsets.
+    ///
+    /// # Returns
+    /// * The normalized characters, each inheriting the offset of the character
+    ///   it was normalized from.
+    fn normalize_chars(&self, chars: &[(char, usize)]) -> Vec<(char, usize)> {
+        let mut result = Vec::with_capacity(chars.len());
+        let mut segment = String::new();
+        let mut idx = 0;
+
+        while idx < chars.len() {
+            let (c, offset) = chars[idx];
+
+            let mut end = idx + 1;
+            while end < chars.len() && is_combining_mark(chars[end].0) {
+                end += 1;
+            }
+
+            if c.is_ascii() && end == idx + 1 {
+                result.push((c, offset));
+                idx = end;
+                continue;
+            }
+
+            segment.clear();
+            segment.extend(
+                chars[idx..end]
+                    .iter()
+                    .filter_map(|(c, _)| Self::unify_char(*c)),
+            );
+
+            let normalized: Vec<char> = if self.fold_diacritics {
+                segment
+                    .nfkd()
+                    .filter(|c| !is_combining_mark(*c))
+                    .nfc()
+                    .collect()
+            } else {
+                segment.nfkc().collect()
+            };
+
+            result.extend(
+                normalized
+                    .into_iter()
+                    .filter_map(Self::unify_char)
+                    .map(|c| (c, offset)),
+            );
+
+            idx = end;
+        }
+
+        result
+    }
+
+    /// Unifies typographic variants of apostrophes and dashes, and removes
+    /// invisible characters.
+    ///
+    /// # Arguments
+    /// * `c` - The character to unify.
+    ///
+    /// # Returns
+    /// * The unified character, or `None` if the character is removed.
+    fn unify_char(c: char) -> Option<char> {
+        match c {
+            '\u{2018}' | '\u{2019}' | '\u{201B}' | '\u{2032}' | '\u{02BC}' | '\u{00B4}'
+            | '\u{FF07}' => Some('\''),
+            '\u{2010}' | '\u{2011}' | '\u{2012}' | '\u{2013}' | '\u{2212}' | '\u{FE63}'
+            | '\u{FF0D}' => Some('-'),
+            '\u{2014}' | '\u{2015}' => Some(' '),
+            '\u{00AD}' | '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{2060}' | '\u{FEFF}' => None,
+            c => Some(c),
+        }
+    }
+
+    /// Removes all non-overlapping occurrences of a character sequence, scanning
+    /// from left to right (equivalent to `str::replace` with an empty replacement).
+    ///
+    /// # Arguments
+    /// * `chars` - A slice of characters paired with their original byte offsets.
+    /// * `sequence` - The character sequence to remove.
+    ///
+    /// # Returns
+    /// * The remaining offset-tagged characters.
+    fn remove_sequence(chars: &[(char, usize)], sequence: &[char]) -> Vec<(char, usize)> {
+        Self::substitute_sequence(chars, sequence, None)
+    }
+
+    /// Replaces all non-overlapping occurrences of a character sequence with a
+    /// single character, scanning from left to right. The replacement character
+    /// inherits the byte offset of the first character it replaces.
+    ///
+    /// # Arguments
+    /// * `chars` - A slice of characters paired with their original byte offsets.
+    /// * `sequence` - The character sequence to replace.
+    /// * `replacement` - The character to substitute for each occurrence.
+    ///
+    /// # Returns
+    /// * The resulting offset-tagged characters.
+    fn replace_sequence(
+        chars: &[(char, usize)],
+        sequence: &[char],
+        replacement: char,
+    ) -> Vec<(char, usize)> {
+        Self::substitute_sequence(chars, sequence, Some(replacement))
+    }
+
+    /// Shared implementation of `remove_sequence` and `replace_sequence`.
+    fn substitute_sequence(
+        chars: &[(char, usize)],
+        sequence: &[char],
+        replacement: Option<char>,
+    ) -> Vec<(char, usize)> {
+        let mut result = Vec::with_capacity(chars.len());
+        let mut idx = 0;
+
+        while idx < chars.len() {
+            let is_match = idx + sequence.len() <= chars.len()
+                && chars[idx..idx + sequence.len()]
+                    .iter()
+                    .zip(sequence)
+                    .all(|((c, _), s)| c == s);
+
+            if is_match {
+                if let Some(replacement) = replacement {
+                    result.push((replacement, chars[idx].1));
+                }
+                idx += sequence.len();
+            } else {
+                result.push(chars[idx]);
+                idx += 1;
+            }
+        }
+
+        result
+    }
+
+    /// Determines the byte range covered by a word, from the start of its first
+    /// character to the end of its last character.
+    ///
+    /// Characters may have been normalized, so the end of the last character is
+    /// found in the original text, including any combining marks which follow it.
+    ///
+    /// # Arguments
+    /// * `text` - The original text.
+    /// * `word` - A slice of characters paired with their original byte offsets.
+    ///
+    /// # Returns
+    /// * The byte range, or `None` if the word is empty.
+    fn get_byte_range(text: &str, word: &[(char, usize)]) -> Option<Range<usize>> {
+        let (_, start) = word.first()?;
+        let (_, last_offset) = word.last()?;
+
+        let mut source_chars = text[*last_offset..].chars();
+        let last_char = source_chars.next()?;
+        let combining_marks_len: usize = source_chars
+            .take_while(|c| is_combining_mark(*c))
+            .map(char::len_utf8)
+            .sum();
+
+        Some(*start..last_offset + last_char.len_utf8() + combining_marks_len)
+    }
+
     /// Calculates the ratio of uppercase letters in a word.
     ///
+    /// Market-qualified and share class ticker symbols (e.g., "7203.T" or "BRK-B")
+    /// are measured by their letters alone, as their digits and separators are
+    /// never uppercase.
+    ///
     /// # Arguments
     /// * `word` - A reference to the token to analyze.
     ///
     /// # Returns
     /// * The ratio of uppercase letters in the word as a float.
     fn calc_uppercase_ratio(&self, word: &TokenRef) -> f32 {
+        if word.contains(['.', '-']) {
+            let letters: Vec<char> = word.chars().filter(|c| c.is_alphabetic()).collect();
+            if letters.is_empty() {
+                return 0.0;
+            }
+            let uppercase_letters = letters.iter().filter(|c| c.is_uppercase()).count() as f32;
+            return uppercase_letters / letters.len() as f32;
+        }
+
         let total_chars = word.chars().count() as f32;
         if total_chars == 0.0 {
             return 0.0;
@@ -151,14 +905,6 @@ impl Tokenizer {
         uppercase_chars / total_chars
     }
 
-    /// Preprocesses the stop words by converting them to uppercase.
-    ///
-    /// # Returns
-    /// * A `HashSet` containing the preprocessed stop words.
-    fn preprocess_stop_words() -> HashSet<String> {
-        STOP_WORDS.iter().map(|word| word.to_uppercase()).collect()
-    }
-
     /// Converts tokens to character code vectors.
     ///
     /// # Arguments
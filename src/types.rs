@@ -1,3 +1,4 @@
+use crate::CompanyMetadata;
 use std::collections::HashMap;
 
 // Types listed here are either shared across multiple files and/or exposed via the library.
@@ -35,11 +36,10 @@ pub type CompanyName = String;
 /// variations in naming conventions or aliases for companies.
 pub type AlternateCompanyName = String;
 
-/// A list of company symbols, where each entry includes:
-/// - `TickerSymbol`: The company's stock ticker.
-/// - `Option<CompanyName>`: The company's primary name (optional if not available).
-/// - `Vec<AlternateCompanyName>`: A list of alternate names or aliases for the company.
-pub type CompanySymbolList = Vec<(TickerSymbol, Option<CompanyName>, Vec<AlternateCompanyName>)>;
+/// A list of company symbols, where each entry includes the company's ticker
+/// symbol, its primary name (if available), a list of alternate names or aliases,
+/// and its asset type (if known).
+pub type CompanySymbolList = Vec<CompanyMetadata>;
 
 /// Represents a ticker symbol (e.g., stock ticker) as an owned `String`.
 pub type TickerSymbol = String;
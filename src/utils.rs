@@ -4,5 +4,8 @@ pub use count_ticker_symbol_frequencies::count_ticker_symbol_frequencies;
 pub mod dedup_vector;
 pub use dedup_vector::dedup_vector;
 
+pub mod normalize_ticker_symbol;
+pub use normalize_ticker_symbol::normalize_ticker_symbol;
+
 pub mod sort_results;
 pub use sort_results::sort_results;
@@ -0,0 +1,95 @@
+use crate::types::TickerSymbol;
+use crate::Market;
+
+/// The characters which data vendors use to separate a ticker symbol from its
+/// share class (e.g., "BRK.B", "BRK/B", "BRK-B", or "BRK B").
+pub(crate) const SHARE_CLASS_SEPARATORS: [char; 5] = ['.', '/', '-', ' ', '_'];
+
+/// Normalizes a ticker symbol to the format used by the company symbol list.
+///
+/// ### Normalization:
+/// - Whitespace is trimmed, and letters are converted to uppercase.
+/// - Share classes are separated with a hyphen, regardless of the separator used
+///   (e.g., "BRK.B", "BRK/B", and "BRK B" become "BRK-B").
+/// - Preferred shares are denoted with a "P" (e.g., "ABR.PRD" and "ABR^D" become
+///   "ABR-PD"), warrants with "WT" (e.g., "ACHR.WS" becomes "ACHR-WT"), and units
+///   with "UN" (e.g., "AACT.U" becomes "AACT-UN").
+/// - Market suffixes are retained (e.g., "VOD.L" is unchanged). A one-letter
+///   suffix which is both a market and a share class (e.g., "MKC.V") is treated
+///   as a market here, as this function has no symbol list to check against;
+///   lookups and extraction prefer a matching U.S. share class entry (e.g.,
+///   "MKC-V") when one exists.
+///
+/// ### Parameters:
+/// - `ticker_symbol`: The ticker symbol, in any of the formats above.
+///
+/// ### Returns:
+/// - The normalized ticker symbol.
+///
+/// ### Example:
+/// ```rust
+/// use ticker_sniffer::normalize_ticker_symbol;
+///
+/// assert_eq!(normalize_ticker_symbol("brk.b"), "BRK-B");
+/// assert_eq!(normalize_ticker_symbol("BRK/B"), "BRK-B");
+/// assert_eq!(normalize_ticker_symbol("ABR^D"), "ABR-PD");
+/// assert_eq!(normalize_ticker_symbol("AAPL"), "AAPL");
+/// ```
+pub fn normalize_ticker_symbol(ticker_symbol: &str) -> TickerSymbol {
+    let ticker_symbol = ticker_symbol.trim().to_uppercase();
+
+    // Market-qualified ticker symbols keep their suffix, and only the symbol
+    // itself is normalized
+    if let Some(suffix) = Market::from_ticker_symbol(&ticker_symbol).suffix() {
+        let base = &ticker_symbol[..ticker_symbol.len() - suffix.len() - 1];
+
+        return format!("{}.{}", normalize_share_class(base), suffix);
+    }
+
+    normalize_share_class(&ticker_symbol)
+}
+
+/// Normalizes a ticker symbol as a U.S. share class ticker symbol, treating any
+/// suffix as a share class, even if it is also a market suffix (e.g., "MKC.V"
+/// becomes "MKC-V").
+///
+/// # Arguments
+/// * `ticker_symbol` - The ticker symbol.
+pub(crate) fn normalize_share_class_ticker_symbol(ticker_symbol: &str) -> TickerSymbol {
+    normalize_share_class(&ticker_symbol.trim().to_uppercase())
+}
+
+/// Normalizes the share class of an uppercased ticker symbol which is not
+/// market-qualified.
+fn normalize_share_class(ticker_symbol: &str) -> TickerSymbol {
+    // CQS-style preferred shares (e.g., "ABR^D")
+    let ticker_symbol = ticker_symbol.replace('^', "-P");
+
+    let parts: Vec<&str> = ticker_symbol
+        .split(|c| SHARE_CLASS_SEPARATORS.contains(&c))
+        .filter(|part| !part.is_empty())
+        .collect();
+
+    let Some((root, share_class_parts)) = parts.split_first() else {
+        return ticker_symbol;
+    };
+
+    if share_class_parts.is_empty() {
+        return root.to_string();
+    }
+
+    let share_class = share_class_parts.concat();
+
+    let share_class = match share_class.as_str() {
+        "WS" => "WT".to_string(),
+        "U" => "UN".to_string(),
+        // A "PR" followed by a series letter denotes a preferred share, while
+        // "PR" alone is series "R" of a preferred share
+        _ if share_class.len() == 3 && share_class.starts_with("PR") => {
+            format!("P{}", &share_class[2..])
+        }
+        _ => share_class,
+    };
+
+    format!("{}-{}", root, share_class)
+}
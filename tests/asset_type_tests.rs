@@ -0,0 +1,158 @@
+use std::str::FromStr;
+use ticker_sniffer::{
+    get_company_metadata, AssetType, CompanySymbolListPreprocessor, CompanyTokenProcessorConfig,
+    Error, TickerSniffer, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+};
+
+#[cfg(test)]
+mod asset_type_tests {
+    use super::*;
+
+    #[test]
+    fn test_embedded_asset_types() {
+        let expected = [
+            ("AAPL", AssetType::Stock),
+            ("DIA", AssetType::Etf),
+            ("AAACX", AssetType::MutualFund),
+            ("BRKHW", AssetType::Warrant),
+            ("BRKHU", AssetType::Unit),
+            ("FBIOP", AssetType::Preferred),
+        ];
+
+        for (ticker_symbol, asset_type) in expected {
+            let company_metadata = get_company_metadata(ticker_symbol).unwrap().unwrap();
+
+            assert_eq!(
+                company_metadata.asset_type,
+                Some(asset_type),
+                "Unexpected asset type for {}",
+                ticker_symbol
+            );
+        }
+    }
+
+    #[test]
+    fn test_embedded_asset_types_are_not_guessed_from_names() {
+        let expected = [
+            // Depositary shares of common stock
+            ("ARM", AssetType::Stock),
+            ("SYT", AssetType::Stock),
+            // A bank named "Preferred Bank"
+            ("PFBC", AssetType::Stock),
+            // Closed-end funds which invest in preferred stock
+            ("PFD", AssetType::Stock),
+            ("FFC", AssetType::Stock),
+            // Exchange-traded notes
+            ("BERZ", AssetType::Etf),
+            ("USOI", AssetType::Etf),
+            // Preferred stock named after its issuer
+            ("AGNCM", AssetType::Preferred),
+            ("FITBO", AssetType::Preferred),
+        ];
+
+        for (ticker_symbol, asset_type) in expected {
+            let company_metadata = get_company_metadata(ticker_symbol).unwrap().unwrap();
+
+            assert_eq!(
+                company_metadata.asset_type,
+                Some(asset_type),
+                "Unexpected asset type for {}",
+                ticker_symbol
+            );
+        }
+
+        let config = CompanyTokenProcessorConfig::builder()
+            .excluded_asset_types([AssetType::Preferred])
+            .build()
+            .unwrap();
+        let results = TickerSniffer::new(&config, true)
+            .unwrap()
+            .extract("Arm Holdings and Preferred Bank shares rose")
+            .unwrap();
+
+        assert!(results.contains_key("ARM"));
+        assert!(results.contains_key("PFBC"));
+    }
+
+    #[test]
+    fn test_asset_type_round_trip() {
+        for asset_type in AssetType::ALL {
+            assert_eq!(
+                AssetType::from_str(&asset_type.to_string()).unwrap(),
+                asset_type
+            );
+        }
+
+        assert_eq!(
+            AssetType::from_str("mutual fund").unwrap(),
+            AssetType::MutualFund
+        );
+        assert!(matches!(
+            AssetType::from_str("Bond"),
+            Err(Error::ParserError(_))
+        ));
+    }
+
+    #[test]
+    fn test_results_report_asset_type() {
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        let results = ticker_sniffer
+            .extract_results("Apple and the SPDR Dow Jones Industrial Average ETF Trust")
+            .unwrap();
+
+        let get_asset_type = |ticker_symbol: &str| {
+            results
+                .iter()
+                .find(|result| result.ticker_symbol == ticker_symbol)
+                .and_then(|result| result.asset_type)
+        };
+
+        assert_eq!(get_asset_type("AAPL"), Some(AssetType::Stock));
+        assert_eq!(get_asset_type("DIA"), Some(AssetType::Etf));
+    }
+
+    #[test]
+    fn test_excluded_and_included_asset_types() {
+        let text = "Apple and the SPDR Dow Jones Industrial Average ETF Trust";
+
+        let config = CompanyTokenProcessorConfig::builder()
+            .excluded_asset_types([AssetType::Etf])
+            .build()
+            .unwrap();
+        let results = TickerSniffer::new(&config, true)
+            .unwrap()
+            .extract(text)
+            .unwrap();
+
+        assert!(results.contains_key("AAPL"));
+        assert!(!results.contains_key("DIA"));
+
+        let config = CompanyTokenProcessorConfig::builder()
+            .included_asset_types([AssetType::Etf])
+            .build()
+            .unwrap();
+        let results = TickerSniffer::new(&config, true)
+            .unwrap()
+            .extract(text)
+            .unwrap();
+
+        assert!(!results.contains_key("AAPL"));
+        assert!(results.contains_key("DIA"));
+    }
+
+    #[test]
+    fn test_invalid_asset_type_cell() {
+        let csv_str =
+            "Symbol,Company Name,Asset Type\nACME,Acme Corporation,Stock\nGLBX,Globex,Bond\n";
+
+        match CompanySymbolListPreprocessor::read_company_symbol_list_from_string(csv_str) {
+            Err(Error::CsvRowError { line, column, .. }) => {
+                assert_eq!(line, Some(3));
+                assert_eq!(column.as_deref(), Some("Asset Type"));
+            }
+            other => panic!("Expected CsvRowError, got {:?}", other),
+        }
+    }
+}
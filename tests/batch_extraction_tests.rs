@@ -0,0 +1,75 @@
+use ticker_sniffer::{
+    extract_tickers_from_texts, TickerSniffer, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+};
+
+#[cfg(test)]
+mod batch_extraction_tests {
+    use super::*;
+
+    const TEXTS: &[&str] = &[
+        "Apple and Microsoft",
+        "",
+        "Amazon and Walmart compete, but Amazon leads.",
+        "Berkshire Hathaway",
+        "Nothing to see here.",
+    ];
+
+    #[test]
+    fn test_batch_matches_individual_extraction() {
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        let batch_results = ticker_sniffer.extract_batch(TEXTS);
+        assert_eq!(batch_results.len(), TEXTS.len());
+
+        for (text, batch_result) in TEXTS.iter().zip(batch_results) {
+            assert_eq!(batch_result.unwrap(), ticker_sniffer.extract(text).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_batch_results_are_deterministic() {
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        // Repeat the documents so parallel processing has work to distribute
+        let texts: Vec<String> = TEXTS
+            .iter()
+            .cycle()
+            .take(TEXTS.len() * 20)
+            .map(|text| text.to_string())
+            .collect();
+
+        let first_run: Vec<_> = ticker_sniffer
+            .extract_results_batch(&texts)
+            .into_iter()
+            .map(|result| result.unwrap())
+            .collect();
+        let second_run: Vec<_> = ticker_sniffer
+            .extract_results_batch(texts.iter())
+            .into_iter()
+            .map(|result| result.unwrap())
+            .collect();
+
+        assert_eq!(first_run, second_run);
+
+        for (idx, results) in first_run.iter().enumerate() {
+            assert_eq!(
+                results,
+                &ticker_sniffer
+                    .extract_results(TEXTS[idx % TEXTS.len()])
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_extract_tickers_from_texts() {
+        let results = extract_tickers_from_texts(TEXTS.iter().copied(), true).unwrap();
+
+        assert_eq!(results.len(), TEXTS.len());
+        assert_eq!(results[0].as_ref().unwrap().get("MSFT"), Some(&1));
+        assert!(results[1].as_ref().unwrap().is_empty());
+        assert_eq!(results[2].as_ref().unwrap().get("AMZN"), Some(&2));
+    }
+}
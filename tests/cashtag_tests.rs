@@ -0,0 +1,117 @@
+use ticker_sniffer::{
+    CompanyTokenProcessorConfig, TickerSniffer, TickerSymbolMentionKind, TickerSymbolTraceOutcome,
+    Tokenizer, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+};
+
+#[cfg(test)]
+mod cashtag_tests {
+    use super::*;
+
+    #[test]
+    fn test_cashtag_parser() {
+        let tokenizer = Tokenizer::cashtag_parser();
+
+        assert_eq!(
+            tokenizer.tokenize("$AAPL, ($msft) and $TSLA's rally"),
+            vec!["AAPL", "MSFT", "TSLA"]
+        );
+
+        // Dollar amounts and currency prefixes are not cashtags
+        assert!(tokenizer
+            .tokenize("Raised $100 at $3.28 billion, or US$5 and A$100 AAPL")
+            .is_empty());
+    }
+
+    #[test]
+    fn test_cashtag_survives_exact_match_ratio() {
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        // The bare ticker symbol is dropped by the exact match ratio, but the
+        // cashtag is kept
+        let results = ticker_sniffer
+            .extract("Apple, Microsoft and AMZN rallied")
+            .unwrap();
+        assert_eq!(results.get("AMZN"), None);
+
+        let results = ticker_sniffer
+            .extract("Apple, Microsoft and $AMZN rallied")
+            .unwrap();
+        assert_eq!(results.get("AAPL"), Some(&1));
+        assert_eq!(results.get("MSFT"), Some(&1));
+        assert_eq!(results.get("AMZN"), Some(&1));
+
+        let trace = ticker_sniffer
+            .explain("Apple, Microsoft and $AMZN rallied")
+            .unwrap();
+        assert!(trace.ticker_symbol_traces.is_empty());
+        assert_eq!(trace.cashtag_traces.len(), 1);
+        assert_eq!(trace.cashtag_traces[0].ticker_symbol, "AMZN");
+        assert_eq!(
+            trace.cashtag_traces[0].outcome,
+            TickerSymbolTraceOutcome::Kept
+        );
+    }
+
+    #[test]
+    fn test_cashtag_mentions() {
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        let text = "Long $aapl and ($BRK.B), raised $100 at a $3.28 billion valuation";
+        let mentions = ticker_sniffer.extract_mentions(text).unwrap();
+
+        assert_eq!(mentions.len(), 2);
+        assert!(mentions
+            .iter()
+            .all(|mention| mention.kind == TickerSymbolMentionKind::Cashtag));
+
+        assert_eq!(mentions[0].ticker_symbol, "AAPL");
+        assert_eq!(&text[mentions[0].byte_range.clone()], "aapl");
+        assert_eq!(
+            mentions[0].confidence,
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG.cashtag_mention_confidence
+        );
+
+        assert_eq!(mentions[1].ticker_symbol, "BRK-B");
+        assert_eq!(mentions[1].text, "BRK.B");
+    }
+
+    #[test]
+    fn test_cashtag_is_not_counted_twice() {
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        let results = ticker_sniffer.extract("Apple ($AAPL) rose").unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results.get("AAPL"), Some(&1));
+
+        let mentions = ticker_sniffer
+            .extract_mentions("Apple ($AAPL) rose")
+            .unwrap();
+        let kinds: Vec<TickerSymbolMentionKind> =
+            mentions.iter().map(|mention| mention.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                TickerSymbolMentionKind::CompanyName,
+                TickerSymbolMentionKind::Cashtag
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cashtag_mention_confidence() {
+        let config = CompanyTokenProcessorConfig::builder()
+            .cashtag_mention_confidence(0.8)
+            .build()
+            .unwrap();
+        let ticker_sniffer = TickerSniffer::new(&config, true).unwrap();
+
+        let results = ticker_sniffer.extract_results("$NVDA").unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].ticker_symbol, "NVDA");
+        assert_eq!(results[0].confidence, 0.8);
+    }
+}
@@ -0,0 +1,112 @@
+use ticker_sniffer::{
+    get_company_metadata, search_company_metadata, CompanyMetadata, CompanySymbolList,
+    CompanyTokenMapper, Market, TickerSniffer, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+};
+
+#[cfg(test)]
+mod company_metadata_tests {
+    use super::*;
+
+    #[test]
+    fn test_get_company_metadata() {
+        let company_metadata = get_company_metadata("MSFT").unwrap().unwrap();
+
+        assert_eq!(company_metadata.ticker_symbol, "MSFT");
+        assert_eq!(
+            company_metadata.company_name.as_deref(),
+            Some("Microsoft Corporation")
+        );
+        assert_eq!(
+            company_metadata.alternate_company_names,
+            vec!["Microsoft".to_string(), "Microsoft Corp.".to_string()]
+        );
+
+        assert_eq!(
+            get_company_metadata("msft").unwrap(),
+            Some(company_metadata)
+        );
+        assert_eq!(get_company_metadata("NOT-A-TICKER").unwrap(), None);
+    }
+
+    #[test]
+    fn test_company_metadata_from_tuple() {
+        // The tuple form previously used by `CompanySymbolList`
+        let company_symbol_list: CompanySymbolList = vec![
+            (
+                "ACME".to_string(),
+                Some("Acme Corporation".to_string()),
+                vec!["Acme".to_string()],
+            ),
+            ("VOD.L".to_string(), None, vec![]),
+        ]
+        .into_iter()
+        .map(CompanyMetadata::from)
+        .collect();
+
+        assert_eq!(
+            company_symbol_list[0],
+            CompanyMetadata::new("ACME", Some("Acme Corporation"), &["Acme"])
+        );
+        assert_eq!(company_symbol_list[1].market, Market::London);
+
+        let ticker_sniffer = TickerSniffer::with_company_symbol_list(
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+            &company_symbol_list,
+            true,
+        )
+        .unwrap();
+        assert_eq!(
+            ticker_sniffer.extract("Acme").unwrap().get("ACME"),
+            Some(&1)
+        );
+    }
+
+    #[test]
+    fn test_search_company_metadata() {
+        let ticker_symbols: Vec<&str> = search_company_metadata("berkshire")
+            .unwrap()
+            .into_iter()
+            .map(|company_metadata| company_metadata.ticker_symbol.as_str())
+            .collect();
+
+        assert_eq!(ticker_symbols.len(), 4);
+        for ticker_symbol in ["BFOCX", "BHLB", "BRK-A", "BRK-B"] {
+            assert!(ticker_symbols.contains(&ticker_symbol));
+        }
+
+        assert!(search_company_metadata("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_search_ranks_exact_and_primary_name_matches_first() {
+        let company_symbol_list = vec![
+            CompanyMetadata::new("ACMB", Some("Big Acme Holdings"), &[]),
+            CompanyMetadata::new("ACMA", Some("Acme Anvils Corporation"), &[]),
+            CompanyMetadata::new("ACME", Some("Acme"), &["Acme Corp"]),
+            CompanyMetadata::new("ROAD", Some("Roadrunner Inc."), &["Acme Roadrunner"]),
+            CompanyMetadata::new("XACM", Some("Xacme Industries"), &[]),
+        ];
+
+        let company_token_mapper = CompanyTokenMapper::new(&company_symbol_list, true).unwrap();
+
+        let ticker_symbols: Vec<&str> = company_token_mapper
+            .search_company_metadata("ACME")
+            .into_iter()
+            .map(|company_metadata| company_metadata.ticker_symbol.as_str())
+            .collect();
+
+        assert_eq!(ticker_symbols, vec!["ACME", "ACMA", "ROAD", "ACMB", "XACM"]);
+    }
+
+    #[test]
+    fn test_ticker_sniffer_company_metadata() {
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        for (ticker_symbol, _) in ticker_sniffer.extract("Apple and Microsoft").unwrap() {
+            assert!(ticker_sniffer
+                .get_company_metadata(&ticker_symbol)
+                .is_some());
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use ticker_sniffer::{
+    AssetType, CompanyMetadata, CompanyNameConsistencyIssueKind, CompanyTokenProcessorConfig,
+    TickerSniffer, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+};
+
+#[cfg(test)]
+mod company_name_consistency_tests {
+    use super::*;
+
+    fn get_company_symbol_list() -> Vec<CompanyMetadata> {
+        vec![
+            CompanyMetadata::new("ACME", Some("Acme Corporation"), &["Acme", "The"]),
+            CompanyMetadata::new(
+                "FBGRX",
+                Some("Fidelity Blue Chip Growth Fund"),
+                &["Fidelity Blue Chip"],
+            )
+            .with_asset_type(AssetType::MutualFund),
+            CompanyMetadata::new(
+                "FBCVX",
+                Some("Fidelity Blue Chip Value"),
+                &["Fidelity Blue Chip"],
+            )
+            .with_asset_type(AssetType::MutualFund),
+        ]
+    }
+
+    #[test]
+    fn test_check_company_name_consistency() {
+        let ticker_sniffer = TickerSniffer::with_company_symbol_list(
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+            &get_company_symbol_list(),
+            true,
+        )
+        .unwrap();
+
+        let issues = ticker_sniffer.check_company_name_consistency().unwrap();
+
+        let summary: Vec<(CompanyNameConsistencyIssueKind, &str, &str, Vec<&str>)> = issues
+            .iter()
+            .map(|issue| {
+                (
+                    issue.kind,
+                    issue.ticker_symbol.as_str(),
+                    issue.company_name.as_str(),
+                    issue
+                        .other_ticker_symbols
+                        .iter()
+                        .map(String::as_str)
+                        .collect(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    CompanyNameConsistencyIssueKind::Unresolved,
+                    "ACME",
+                    "The",
+                    vec![]
+                ),
+                (
+                    CompanyNameConsistencyIssueKind::Ambiguous,
+                    "FBCVX",
+                    "Fidelity Blue Chip",
+                    vec!["FBGRX"]
+                ),
+                (
+                    CompanyNameConsistencyIssueKind::Ambiguous,
+                    "FBGRX",
+                    "Fidelity Blue Chip",
+                    vec!["FBCVX"]
+                ),
+            ]
+        );
+
+        assert_eq!(
+            issues[1].to_tsv_row(),
+            "ambiguous\tFBCVX\tFidelity Blue Chip\tFBGRX"
+        );
+    }
+
+    #[test]
+    fn test_check_company_name_consistency_with_excluded_entries() {
+        // Excluded entries are neither checked nor extracted
+        let config = CompanyTokenProcessorConfig::builder()
+            .excluded_asset_types([AssetType::MutualFund])
+            .build()
+            .unwrap();
+
+        let ticker_sniffer =
+            TickerSniffer::with_company_symbol_list(&config, &get_company_symbol_list(), true)
+                .unwrap();
+
+        let issues = ticker_sniffer.check_company_name_consistency().unwrap();
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].ticker_symbol, "ACME");
+        assert_eq!(issues[0].company_name, "The");
+    }
+}
@@ -0,0 +1,232 @@
+use ticker_sniffer::{
+    CompanyMetadata, CompanySymbolList, CompanySymbolListOverlay, CompanySymbolListPatch, Date,
+    Error, TickerSniffer, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+};
+
+#[cfg(test)]
+mod company_symbol_list_overlay_tests {
+    use super::*;
+
+    fn get_base_company_symbol_list() -> CompanySymbolList {
+        vec![
+            CompanyMetadata::new("ACME", Some("Acme Corporation"), &["Acme"]),
+            CompanyMetadata::new("GLBX", None, &["Globex"]),
+            CompanyMetadata::new("INIT", Some("Initech"), &["Initech Inc"]),
+        ]
+    }
+
+    #[test]
+    fn test_apply_patches_in_order() {
+        let overlay = CompanySymbolListOverlay::new(vec![
+            CompanySymbolListPatch::Add(CompanyMetadata::new(
+                "ACME",
+                Some("Ignored Name"),
+                &["Acme", "Acme Corp"],
+            )),
+            CompanySymbolListPatch::Add(CompanyMetadata::new("GLBX", Some("Globex Holdings"), &[])),
+            CompanySymbolListPatch::Replace(CompanyMetadata::new(
+                "INIT",
+                Some("Initech Corporation"),
+                &[],
+            )),
+            CompanySymbolListPatch::Add(CompanyMetadata::new("HOOLI", Some("Hooli"), &[])),
+            CompanySymbolListPatch::Remove {
+                ticker_symbol: "GLBX".to_string(),
+            },
+        ]);
+
+        let mut company_symbol_list = get_base_company_symbol_list();
+        overlay.apply(&mut company_symbol_list);
+
+        assert_eq!(
+            company_symbol_list,
+            vec![
+                CompanyMetadata::new("ACME", Some("Acme Corporation"), &["Acme", "Acme Corp"]),
+                CompanyMetadata::new("INIT", Some("Initech Corporation"), &[]),
+                CompanyMetadata::new("HOOLI", Some("Hooli"), &[]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_apply_patches_to_duplicate_and_historical_entries() {
+        let historical_until = Some(Date::new(2022, 6, 9).unwrap());
+        let reused_from = Some(Date::new(2024, 1, 2).unwrap());
+
+        let get_company_symbol_list = || {
+            vec![
+                CompanyMetadata::new("FB", Some("Facebook, Inc."), &["Facebook"])
+                    .with_validity(None, historical_until),
+                CompanyMetadata::new("CAMP", Some("CAMP4 Therapeutics Corporation"), &[]),
+                CompanyMetadata::new("FB", Some("Fictional Bank"), &[])
+                    .with_validity(reused_from, None),
+                CompanyMetadata::new("CAMP", Some("CalAmp Corp."), &[]),
+            ]
+        };
+
+        // Current patches apply to every current entry, but not historical ones
+        let overlay = CompanySymbolListOverlay::new(vec![
+            CompanySymbolListPatch::Add(CompanyMetadata::new("CAMP", None, &["CAMP4"])),
+            CompanySymbolListPatch::Add(CompanyMetadata::new("FB", None, &["FB Bank"])),
+        ]);
+
+        let mut company_symbol_list = get_company_symbol_list();
+        overlay.apply(&mut company_symbol_list);
+
+        assert_eq!(
+            company_symbol_list[0].alternate_company_names,
+            vec!["Facebook".to_string()]
+        );
+        assert_eq!(
+            company_symbol_list[1].alternate_company_names,
+            vec!["CAMP4".to_string()]
+        );
+        assert_eq!(
+            company_symbol_list[2].alternate_company_names,
+            vec!["FB Bank".to_string()]
+        );
+        assert_eq!(
+            company_symbol_list[3].alternate_company_names,
+            vec!["CAMP4".to_string()]
+        );
+
+        // Replacing a duplicated ticker symbol leaves a single entry, and a
+        // historical entry is only replaced by a patch for its validity period
+        let overlay = CompanySymbolListOverlay::new(vec![
+            CompanySymbolListPatch::Replace(CompanyMetadata::new(
+                "CAMP",
+                Some("CAMP4 Therapeutics Corporation"),
+                &["CAMP4"],
+            )),
+            CompanySymbolListPatch::Replace(
+                CompanyMetadata::new("FB", Some("Facebook, Inc."), &["Facebook", "The Facebook"])
+                    .with_validity(None, historical_until),
+            ),
+        ]);
+
+        let mut company_symbol_list = get_company_symbol_list();
+        overlay.apply(&mut company_symbol_list);
+
+        assert_eq!(
+            company_symbol_list,
+            vec![
+                CompanyMetadata::new("FB", Some("Facebook, Inc."), &["Facebook", "The Facebook"])
+                    .with_validity(None, historical_until),
+                CompanyMetadata::new("CAMP", Some("CAMP4 Therapeutics Corporation"), &["CAMP4"]),
+                CompanyMetadata::new("FB", Some("Fictional Bank"), &[])
+                    .with_validity(reused_from, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_csv_str() {
+        let csv_str = "\
+Action,Symbol,Company Name,Alternate Names
+add,ibm,,\"Big Blue, IBM Corp\"
+remove,GLBX,,
+REPLACE,INIT,Initech Corporation,
+";
+
+        let overlay = CompanySymbolListOverlay::from_csv_str(csv_str).unwrap();
+
+        assert_eq!(
+            overlay.patches,
+            vec![
+                CompanySymbolListPatch::Add(CompanyMetadata::new(
+                    "IBM",
+                    None,
+                    &["Big Blue", "IBM Corp"]
+                )),
+                CompanySymbolListPatch::Remove {
+                    ticker_symbol: "GLBX".to_string(),
+                },
+                CompanySymbolListPatch::Replace(CompanyMetadata::new(
+                    "INIT",
+                    Some("Initech Corporation"),
+                    &[]
+                )),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_csv_str_errors() {
+        assert!(matches!(
+            CompanySymbolListOverlay::from_csv_str("Symbol,Company Name\nIBM,IBM\n"),
+            Err(Error::MissingHeaderError(ref header)) if header == "Action"
+        ));
+
+        match CompanySymbolListOverlay::from_csv_str("Action,Symbol\nadd,IBM\nrename,GLBX\n") {
+            Err(Error::CsvRowError { line, column, .. }) => {
+                assert_eq!(line, Some(3));
+                assert_eq!(column.as_deref(), Some("Action"));
+            }
+            result => panic!("Unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_ticker_sniffer_with_overlay() {
+        let csv_str = "\
+Action,Symbol,Company Name,Alternate Names
+add,IBM,,Big Blue
+remove,AAPL,,
+";
+        let overlay = CompanySymbolListOverlay::from_csv_str(csv_str).unwrap();
+
+        let ticker_sniffer =
+            TickerSniffer::with_overlay(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, &overlay, true)
+                .unwrap();
+
+        let results = ticker_sniffer
+            .extract("Big Blue, Apple and Microsoft reported earnings.")
+            .unwrap();
+
+        assert_eq!(results.get("IBM"), Some(&1));
+        assert_eq!(results.get("MSFT"), Some(&1));
+        assert_eq!(results.get("AAPL"), None);
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_from_toml_str() {
+        let toml_str = r#"
+            [[patches]]
+            action = "add"
+            ticker_symbol = "ibm"
+            alternate_company_names = ["Big Blue"]
+
+            [[patches]]
+            action = "remove"
+            ticker_symbol = "GLBX"
+
+            [[patches]]
+            action = "replace"
+            ticker_symbol = "INIT"
+            company_name = "Initech Corporation"
+        "#;
+
+        let overlay = CompanySymbolListOverlay::from_toml_str(toml_str).unwrap();
+
+        assert_eq!(
+            overlay.patches,
+            vec![
+                CompanySymbolListPatch::Add(CompanyMetadata::new("IBM", None, &["Big Blue"])),
+                CompanySymbolListPatch::Remove {
+                    ticker_symbol: "GLBX".to_string(),
+                },
+                CompanySymbolListPatch::Replace(CompanyMetadata::new(
+                    "INIT",
+                    Some("Initech Corporation"),
+                    &[]
+                )),
+            ]
+        );
+
+        assert!(matches!(
+            CompanySymbolListOverlay::from_toml_str("[[patches]]\naction = \"rename\""),
+            Err(Error::TomlError(_))
+        ));
+    }
+}
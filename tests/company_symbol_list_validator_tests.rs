@@ -0,0 +1,190 @@
+use ticker_sniffer::{
+    CompanyMetadata, CompanySymbolListIssue, CompanySymbolListIssueKind,
+    CompanySymbolListPreprocessor, CompanySymbolListValidator, CompanyTokenProcessorConfig, Date,
+    StopWordPreset, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+};
+
+#[cfg(test)]
+mod company_symbol_list_validator_tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_issue_kinds() {
+        let company_symbol_list = vec![
+            CompanyMetadata::new("ACME", Some("Acme Corporation"), &["Acme", ""]),
+            CompanyMetadata::new("ROAD", Some("Roadrunner Inc."), &["Acme Corporation"]),
+            CompanyMetadata::new("ALL", Some("Allstate Corp"), &["The And"]),
+            CompanyMetadata::new("ACME", Some("Acme Corp"), &[]),
+        ];
+
+        let issues = CompanySymbolListValidator::validate(
+            &company_symbol_list,
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+        );
+
+        let summary: Vec<(usize, CompanySymbolListIssueKind, &str, Option<usize>)> = issues
+            .iter()
+            .map(|issue| {
+                (
+                    issue.entry_idx,
+                    issue.kind,
+                    issue.value.as_str(),
+                    issue.related_entry_idx,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                (0, CompanySymbolListIssueKind::EmptyCompanyName, "", None),
+                (
+                    1,
+                    CompanySymbolListIssueKind::AlternateNameMatchesCompanyName,
+                    "Acme Corporation",
+                    Some(0)
+                ),
+                (
+                    2,
+                    CompanySymbolListIssueKind::StopWordCompanyName,
+                    "The And",
+                    None
+                ),
+                (
+                    2,
+                    CompanySymbolListIssueKind::StopWordTickerSymbol,
+                    "ALL",
+                    None
+                ),
+                (
+                    3,
+                    CompanySymbolListIssueKind::DuplicateTickerSymbol,
+                    "ACME",
+                    Some(0)
+                ),
+            ]
+        );
+
+        assert_eq!(issues[1].related_ticker_symbol.as_deref(), Some("ACME"));
+    }
+
+    #[test]
+    fn test_validate_with_configured_stop_words() {
+        let company_symbol_list = vec![
+            CompanyMetadata::new("DER", Some("Der Und"), &[]),
+            CompanyMetadata::new("THE", Some("The And"), &[]),
+        ];
+
+        let config = CompanyTokenProcessorConfig::builder()
+            .stop_word_preset(StopWordPreset::German)
+            .build()
+            .unwrap();
+
+        let issues = CompanySymbolListValidator::validate(&company_symbol_list, &config);
+
+        let summary: Vec<(usize, CompanySymbolListIssueKind)> = issues
+            .iter()
+            .map(|issue| (issue.entry_idx, issue.kind))
+            .collect();
+
+        // English stop words are ordinary words with the German preset
+        assert_eq!(
+            summary,
+            vec![
+                (0, CompanySymbolListIssueKind::StopWordCompanyName),
+                (0, CompanySymbolListIssueKind::StopWordTickerSymbol),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_historical_ticker_symbols() {
+        // Reused ticker symbols are not duplicates if their validity periods do not overlap
+        let company_symbol_list = vec![
+            CompanyMetadata::new("FB", Some("Facebook, Inc."), &[])
+                .with_validity(None, Some(Date::new(2022, 6, 9).unwrap())),
+            CompanyMetadata::new("FB", Some("ProShares S&P 500 Dynamic Buffer ETF"), &[])
+                .with_validity(Some(Date::new(2022, 6, 9).unwrap()), None),
+            CompanyMetadata::new("TWTR", Some("Twitter, Inc."), &[])
+                .with_validity(None, Some(Date::new(2022, 11, 8).unwrap())),
+            CompanyMetadata::new("TWTR", Some("Twitter"), &[])
+                .with_validity(Some(Date::new(2022, 1, 1).unwrap()), None),
+        ];
+
+        let issues = CompanySymbolListValidator::validate(
+            &company_symbol_list,
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+        );
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].kind,
+            CompanySymbolListIssueKind::DuplicateTickerSymbol
+        );
+        assert_eq!(issues[0].entry_idx, 3);
+        assert_eq!(issues[0].related_entry_idx, Some(2));
+    }
+
+    #[test]
+    fn test_issue_tsv_row() {
+        let company_symbol_list = vec![
+            CompanyMetadata::new("ACME", Some("Acme Corporation"), &[]),
+            CompanyMetadata::new("ROAD", None, &["Acme\tCorporation"]),
+        ];
+
+        let issues = CompanySymbolListValidator::validate(
+            &company_symbol_list,
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+        );
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].to_tsv_row(),
+            "1\talternate_name_matches_company_name\tROAD\tAcme Corporation\t0\tACME"
+        );
+        assert_eq!(
+            issues[0].to_tsv_row().split('\t').count(),
+            CompanySymbolListIssue::TSV_HEADER.split('\t').count()
+        );
+    }
+
+    #[test]
+    fn test_parse_issue_kind() {
+        for kind in CompanySymbolListIssueKind::ALL {
+            assert_eq!(
+                kind.as_str().parse::<CompanySymbolListIssueKind>().unwrap(),
+                kind
+            );
+        }
+
+        assert!("unknown".parse::<CompanySymbolListIssueKind>().is_err());
+    }
+
+    #[test]
+    fn test_validate_embedded_company_symbol_list() {
+        let company_symbol_list =
+            CompanySymbolListPreprocessor::read_company_symbol_list_from_path(
+                "data/company_symbol_list.csv",
+            )
+            .unwrap();
+
+        let issues = CompanySymbolListValidator::validate(
+            &company_symbol_list,
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+        );
+
+        // Every primary company name can be matched
+        assert!(!issues.iter().any(|issue| {
+            matches!(
+                issue.kind,
+                CompanySymbolListIssueKind::EmptyCompanyName
+                    | CompanySymbolListIssueKind::StopWordCompanyName
+            ) && Some(&issue.value) == company_symbol_list[issue.entry_idx].company_name.as_ref()
+        }));
+
+        assert!(issues.iter().any(|issue| {
+            issue.kind == CompanySymbolListIssueKind::StopWordTickerSymbol
+                && issue.ticker_symbol == "ALL"
+        }));
+    }
+}
@@ -0,0 +1,98 @@
+use ticker_sniffer::{
+    CompanyTokenProcessorConfig, Error, TickerSniffer, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+};
+
+#[cfg(test)]
+mod company_token_processor_config_tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config() {
+        assert_eq!(
+            &CompanyTokenProcessorConfig::default(),
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG
+        );
+        assert_eq!(
+            &CompanyTokenProcessorConfig::builder().build().unwrap(),
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG
+        );
+        assert!(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG.validate().is_ok());
+    }
+
+    #[test]
+    fn test_builder_overrides_only_set_fields() {
+        let config = CompanyTokenProcessorConfig::builder()
+            .threshold_ratio_exact_matches(0.25)
+            .build()
+            .unwrap();
+
+        assert_eq!(config.threshold_ratio_exact_matches, 0.25);
+        assert_eq!(
+            config.threshold_min_company_token_coverage,
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG.threshold_min_company_token_coverage
+        );
+    }
+
+    #[test]
+    fn test_builder_rejects_out_of_range_values() {
+        let invalid_builders = [
+            CompanyTokenProcessorConfig::builder().threshold_ratio_exact_matches(-0.1),
+            CompanyTokenProcessorConfig::builder().threshold_min_company_token_coverage(1.5),
+            CompanyTokenProcessorConfig::builder().min_ticker_symbol_uppercase_ratio(f32::NAN),
+            CompanyTokenProcessorConfig::builder().ticker_symbol_mention_confidence(2.0),
+            CompanyTokenProcessorConfig::builder().cashtag_mention_confidence(-1.0),
+            CompanyTokenProcessorConfig::builder().exchange_prefixed_mention_confidence(1.1),
+        ];
+
+        for builder in invalid_builders {
+            assert!(matches!(builder.build(), Err(Error::ConfigError(_))));
+        }
+    }
+
+    #[test]
+    fn test_ticker_sniffer_rejects_invalid_config() {
+        let config = CompanyTokenProcessorConfig {
+            threshold_min_company_token_coverage: 1.5,
+            ..CompanyTokenProcessorConfig::default()
+        };
+
+        assert!(matches!(
+            TickerSniffer::new(&config, true),
+            Err(Error::ConfigError(_))
+        ));
+    }
+
+    #[test]
+    fn test_min_ticker_symbol_uppercase_ratio() {
+        let text = "Shares of MSFt rose";
+
+        let default_ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+        assert!(default_ticker_sniffer.extract(text).unwrap().is_empty());
+
+        let config = CompanyTokenProcessorConfig::builder()
+            .min_ticker_symbol_uppercase_ratio(0.7)
+            .build()
+            .unwrap();
+        let ticker_sniffer = TickerSniffer::new(&config, true).unwrap();
+        assert_eq!(ticker_sniffer.extract(text).unwrap().get("MSFT"), Some(&1));
+    }
+
+    #[test]
+    fn test_ticker_symbol_mention_confidence() {
+        let config = CompanyTokenProcessorConfig::builder()
+            .ticker_symbol_mention_confidence(0.8)
+            .build()
+            .unwrap();
+        let ticker_sniffer = TickerSniffer::new(&config, true).unwrap();
+
+        let mentions = ticker_sniffer
+            .extract_mentions("AAPL and MSFT are up")
+            .unwrap();
+
+        assert_eq!(mentions.len(), 2);
+        for mention in mentions {
+            assert_eq!(mention.confidence, 0.8);
+        }
+    }
+}
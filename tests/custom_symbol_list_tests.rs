@@ -0,0 +1,98 @@
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use ticker_sniffer::{TickerSniffer, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG};
+
+#[cfg(test)]
+mod custom_symbol_list_tests {
+    use super::*;
+
+    const CUSTOM_CSV: &str = "\
+\"Symbol\",\"Company Name\",\"Alternate Names\"
+\"ACME\",\"Acme Corporation\",\"Acme, Acme Corp\"
+\"GLBX\",\"Globex Holdings\",\"Globex\"
+";
+
+    const TEXT: &str = "Globex is in talks to acquire Acme Corp.";
+
+    fn gzip(data: &str) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data.as_bytes()).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    fn get_temp_file_path(file_name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "ticker_sniffer_{}_{}",
+            std::process::id(),
+            file_name
+        ))
+    }
+
+    fn assert_custom_results(ticker_sniffer: &TickerSniffer) {
+        let results = ticker_sniffer.extract(TEXT).unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results.get("ACME"), Some(&1));
+        assert_eq!(results.get("GLBX"), Some(&1));
+    }
+
+    #[test]
+    fn test_with_csv_reader() {
+        let ticker_sniffer = TickerSniffer::with_csv_reader(
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+            CUSTOM_CSV.as_bytes(),
+            true,
+        )
+        .unwrap();
+
+        assert_custom_results(&ticker_sniffer);
+    }
+
+    #[test]
+    fn test_with_gzip_csv_reader() {
+        let compressed = gzip(CUSTOM_CSV);
+
+        let ticker_sniffer = TickerSniffer::with_gzip_csv_reader(
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+            compressed.as_slice(),
+            true,
+        )
+        .unwrap();
+
+        assert_custom_results(&ticker_sniffer);
+    }
+
+    #[test]
+    fn test_with_csv_path() {
+        let csv_path = get_temp_file_path("custom_symbol_list.csv");
+        let gzip_path = get_temp_file_path("custom_symbol_list.csv.gz");
+
+        fs::write(&csv_path, CUSTOM_CSV).unwrap();
+        fs::write(&gzip_path, gzip(CUSTOM_CSV)).unwrap();
+
+        for path in [&csv_path, &gzip_path] {
+            let ticker_sniffer =
+                TickerSniffer::with_csv_path(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, path, true)
+                    .unwrap();
+
+            assert_custom_results(&ticker_sniffer);
+        }
+
+        fs::remove_file(csv_path).unwrap();
+        fs::remove_file(gzip_path).unwrap();
+    }
+
+    #[test]
+    fn test_with_missing_csv_path() {
+        let result = TickerSniffer::with_csv_path(
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+            get_temp_file_path("missing_symbol_list.csv"),
+            true,
+        );
+
+        assert!(matches!(result, Err(ticker_sniffer::Error::IoError(_))));
+    }
+}
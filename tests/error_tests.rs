@@ -0,0 +1,97 @@
+use std::error::Error as StdError;
+use ticker_sniffer::{
+    CompanyMetadata, CompanySymbolListPreprocessor, CompanyTokenMapper,
+    CompanyTokenProcessorConfig, Error,
+};
+
+#[cfg(test)]
+mod error_tests {
+    use super::*;
+
+    fn build_invalid_config() -> Result<CompanyTokenProcessorConfig, Box<dyn StdError>> {
+        let config = CompanyTokenProcessorConfig::builder()
+            .threshold_ratio_exact_matches(2.0)
+            .build()?;
+
+        Ok(config)
+    }
+
+    #[test]
+    fn test_error_converts_into_boxed_std_error() {
+        let err = build_invalid_config().unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::ConfigError(_))
+        ));
+    }
+
+    #[test]
+    fn test_missing_header_error() {
+        let csv_str = "Ticker,Company Name\nAAPL,Apple Inc.\n";
+
+        let err = CompanySymbolListPreprocessor::read_company_symbol_list_from_string(csv_str)
+            .unwrap_err();
+
+        assert!(matches!(err, Error::MissingHeaderError(ref header) if header == "Symbol"));
+    }
+
+    #[test]
+    fn test_csv_row_error_with_missing_field() {
+        let csv_str = "Symbol,Company Name,Alternate Names\nAAPL,Apple Inc.,\n,Unknown,\n";
+
+        let err = CompanySymbolListPreprocessor::read_company_symbol_list_from_string(csv_str)
+            .unwrap_err();
+
+        match err {
+            Error::CsvRowError {
+                line,
+                ref column,
+                source,
+                ..
+            } => {
+                assert_eq!(line, Some(3));
+                assert_eq!(column.as_deref(), Some("Symbol"));
+                assert!(source.is_none());
+            }
+            _ => panic!("Unexpected error: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn test_csv_row_error_chains_source() {
+        let csv_str = "Symbol,Company Name,Alternate Names\nAAPL,Apple Inc.,\nMSFT\n";
+
+        let err = CompanySymbolListPreprocessor::read_company_symbol_list_from_string(csv_str)
+            .unwrap_err();
+
+        assert!(matches!(err, Error::CsvRowError { line: Some(3), .. }));
+        assert!(err.source().is_some());
+        assert!(err.to_string().contains("line 3"));
+    }
+
+    #[test]
+    fn test_decompression_error() {
+        let err =
+            CompanySymbolListPreprocessor::extract_company_symbol_list_from_bytes(b"not gzip")
+                .unwrap_err();
+
+        assert!(matches!(err, Error::DecompressionError(_)));
+        assert!(err.source().is_some());
+    }
+
+    #[test]
+    fn test_unknown_token_id_and_ticker_symbol_errors() {
+        let company_symbol_list = vec![CompanyMetadata::new("ACME", Some("Acme Corporation"), &[])];
+        let company_token_mapper = CompanyTokenMapper::new(&company_symbol_list, true).unwrap();
+
+        assert!(matches!(
+            company_token_mapper.get_ticker_symbol_by_token_id(&usize::MAX),
+            Err(Error::UnknownTokenIdError(usize::MAX))
+        ));
+        assert!(matches!(
+            company_token_mapper.get_ticker_symbol_token_id(&"ZZZZ".to_string()),
+            Err(Error::UnknownTickerSymbolError(ref ticker_symbol)) if ticker_symbol == "ZZZZ"
+        ));
+    }
+}
@@ -0,0 +1,173 @@
+use ticker_sniffer::{
+    CompanySymbolListOverlay, CompanyTokenProcessorConfig, Market, MarkupFormat, TickerSniffer,
+    TickerSymbolMentionKind, TickerSymbolTraceOutcome, Tokenizer,
+    DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+};
+
+#[cfg(test)]
+mod exchange_prefixed_tests {
+    use super::*;
+
+    #[test]
+    fn test_exchange_prefixed_parser() {
+        let tokenizer = Tokenizer::exchange_prefixed_parser(true);
+
+        for (text, expected) in [
+            ("Apple (NASDAQ: AAPL) rose", "AAPL"),
+            ("Walmart NYSE:WMT", "WMT"),
+            ("Nasdaq: MSFT", "MSFT"),
+            ("(NYSE American: XYZ)", "XYZ"),
+            ("OTC: ABCD", "ABCD"),
+            ("(NASDAQ Global Select: AMZN)", "AMZN"),
+            ("Ford (NYSE: F)", "F"),
+        ] {
+            assert_eq!(tokenizer.tokenize(text), vec![expected], "{}", text);
+        }
+
+        // Colons which do not follow an exchange name, or are not followed by an
+        // uppercase ticker symbol, are ignored
+        assert!(tokenizer
+            .tokenize("Nasdaq: up 3% at 10:30, Note: AAPL")
+            .is_empty());
+    }
+
+    #[test]
+    fn test_exchange_prefixed_survives_exact_match_ratio() {
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        let results = ticker_sniffer
+            .extract("Apple, Microsoft and (NASDAQ: AMZN) rallied")
+            .unwrap();
+        assert_eq!(results.get("AAPL"), Some(&1));
+        assert_eq!(results.get("MSFT"), Some(&1));
+        assert_eq!(results.get("AMZN"), Some(&1));
+
+        // The exchange name is not matched as a company name
+        assert_eq!(results.get("NDAQ"), None);
+
+        let trace = ticker_sniffer
+            .explain("Apple, Microsoft and (NASDAQ: AMZN) rallied")
+            .unwrap();
+        assert_eq!(trace.exchange_prefixed_traces.len(), 1);
+        assert_eq!(trace.exchange_prefixed_traces[0].ticker_symbol, "AMZN");
+        assert_eq!(
+            trace.exchange_prefixed_traces[0].outcome,
+            TickerSymbolTraceOutcome::Kept
+        );
+    }
+
+    #[test]
+    fn test_exchange_prefixed_mentions() {
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        let text = "Shares of Walmart Inc. (NYSE:WMT) and Ford (NYSE: F) fell";
+        let mentions = ticker_sniffer.extract_mentions(text).unwrap();
+
+        let summary: Vec<(&str, &str, TickerSymbolMentionKind)> = mentions
+            .iter()
+            .map(|mention| {
+                (
+                    mention.ticker_symbol.as_str(),
+                    &text[mention.byte_range.clone()],
+                    mention.kind,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                ("WMT", "Walmart Inc", TickerSymbolMentionKind::CompanyName),
+                ("WMT", "NYSE:WMT", TickerSymbolMentionKind::ExchangePrefixed),
+                ("F", "Ford", TickerSymbolMentionKind::CompanyName),
+                ("F", "NYSE: F", TickerSymbolMentionKind::ExchangePrefixed),
+            ]
+        );
+        assert_eq!(
+            mentions[1].confidence,
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG.exchange_prefixed_mention_confidence
+        );
+
+        let results = ticker_sniffer.extract(text).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results.get("WMT"), Some(&1));
+        assert_eq!(results.get("F"), Some(&1));
+    }
+
+    #[test]
+    fn test_repeated_exchange_prefix() {
+        let tokenizer = Tokenizer::exchange_prefixed_parser(true);
+
+        let spans = tokenizer.tokenize_with_spans("Listed on NYSE: NYSE: WMT today.");
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].token, "WMT");
+        assert_eq!(spans[0].surface, "NYSE: WMT");
+        assert_eq!(spans[0].char_range, 16..25);
+
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        let text = "Listed on NYSE: NYSE: WMT today.";
+        let mentions = ticker_sniffer.extract_mentions(text).unwrap();
+        assert_eq!(mentions.len(), 1);
+        assert_eq!(mentions[0].ticker_symbol, "WMT");
+        assert_eq!(&text[mentions[0].byte_range.clone()], "NYSE: WMT");
+
+        let html = "<p>(NYSE: NYSE: WMT)</p>";
+        let mentions = ticker_sniffer
+            .extract_markup_mentions(html, MarkupFormat::Html)
+            .unwrap();
+        assert_eq!(mentions.len(), 1);
+        assert_eq!(mentions[0].ticker_symbol, "WMT");
+        assert_eq!(&html[mentions[0].byte_range.clone()], "NYSE: WMT");
+    }
+
+    #[test]
+    fn test_exchange_prefixed_mention_confidence() {
+        let config = CompanyTokenProcessorConfig::builder()
+            .exchange_prefixed_mention_confidence(0.9)
+            .build()
+            .unwrap();
+        let ticker_sniffer = TickerSniffer::new(&config, true).unwrap();
+
+        let results = ticker_sniffer.extract_results("Nasdaq: NVDA").unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].ticker_symbol, "NVDA");
+        assert_eq!(results[0].confidence, 0.9);
+    }
+
+    #[test]
+    fn test_exchange_prefixed_market() {
+        let overlay = CompanySymbolListOverlay::from_csv_str(
+            "\
+Action,Symbol,Company Name,Alternate Names,Market
+add,SHOP.TO,Shopify Inc.,,
+add,VOD,Vodafone Group Plc,Vodafone,London
+",
+        )
+        .unwrap();
+
+        let ticker_sniffer =
+            TickerSniffer::with_overlay(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, &overlay, true)
+                .unwrap();
+
+        // The exchange name qualifies the ticker symbol with its market, rather
+        // than matching the US listings of "SHOP" and "VOD"
+        let results = ticker_sniffer
+            .extract_results("Shares rose on the TSX: SHOP and LSE: VOD")
+            .unwrap();
+
+        let mut ticker_symbols: Vec<(&str, Market)> = results
+            .iter()
+            .map(|result| (result.ticker_symbol.as_str(), result.market))
+            .collect();
+        ticker_symbols.sort_by_key(|(ticker_symbol, _)| *ticker_symbol);
+        assert_eq!(
+            ticker_symbols,
+            vec![("SHOP.TO", Market::Toronto), ("VOD.L", Market::London)]
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use ticker_sniffer::{
+    TickerSniffer, TickerSymbolTrace, TickerSymbolTraceOutcome,
+    DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+};
+
+#[cfg(test)]
+mod extraction_trace_tests {
+    use super::*;
+
+    fn find_ticker_symbol_trace<'a>(
+        ticker_symbol_traces: &'a [TickerSymbolTrace],
+        ticker_symbol: &str,
+    ) -> &'a TickerSymbolTrace {
+        ticker_symbol_traces
+            .iter()
+            .find(|ticker_symbol_trace| ticker_symbol_trace.ticker_symbol == ticker_symbol)
+            .unwrap_or_else(|| panic!("Expected a trace for {}", ticker_symbol))
+    }
+
+    #[test]
+    fn test_trace_matches_extraction_results() {
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        let text = "Microsoft rose. Also AMZN, AAPL, TSLA, NVDA.";
+        let trace = ticker_sniffer.explain(text).unwrap();
+
+        assert_eq!(
+            trace.ticker_symbol_frequency_map,
+            ticker_sniffer.extract(text).unwrap()
+        );
+        assert!(!trace.text_doc_tokens.is_empty());
+        assert_eq!(
+            trace.query_text_doc_token_ids.len(),
+            trace.query_text_doc_token_spans.len()
+        );
+        assert!(!trace.top_range_states.is_empty());
+    }
+
+    #[test]
+    fn test_trace_records_symbols_dropped_by_exact_match_ratio() {
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        let trace = ticker_sniffer.explain("Apple, Microsoft and AMZN").unwrap();
+
+        assert!(trace.ratio_exact_matches < trace.threshold_ratio_exact_matches);
+
+        let amzn_trace = find_ticker_symbol_trace(&trace.ticker_symbol_traces, "AMZN");
+        assert_eq!(
+            amzn_trace.outcome,
+            TickerSymbolTraceOutcome::DroppedByExactMatchRatio
+        );
+        assert_eq!(amzn_trace.frequency, 0);
+        assert_eq!(amzn_trace.token_spans.len(), 1);
+        assert_eq!(amzn_trace.token_spans[0].surface, "AMZN");
+        assert_eq!(trace.ticker_symbol_frequency_map.get("AMZN"), None);
+    }
+
+    #[test]
+    fn test_trace_records_kept_symbols() {
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        let trace = ticker_sniffer.explain("AAPL and MSFT are up").unwrap();
+
+        assert!(trace.ratio_exact_matches >= trace.threshold_ratio_exact_matches);
+
+        assert_eq!(
+            find_ticker_symbol_trace(&trace.ticker_symbol_traces, "AAPL").outcome,
+            TickerSymbolTraceOutcome::MatchedByCompanyName
+        );
+
+        let msft_trace = find_ticker_symbol_trace(&trace.ticker_symbol_traces, "MSFT");
+        assert_eq!(msft_trace.outcome, TickerSymbolTraceOutcome::Kept);
+        assert_eq!(msft_trace.frequency, 1);
+    }
+
+    #[test]
+    fn test_trace_separates_discarded_range_states() {
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        let trace = ticker_sniffer
+            .explain("Dow Jones Industrial Average and DOW")
+            .unwrap();
+
+        assert!(!trace.discarded_token_range_states.is_empty());
+
+        for state in &trace.discarded_token_range_states {
+            assert!(state.company_token_coverage < trace.threshold_min_company_token_coverage);
+        }
+
+        for state in &trace.token_range_states {
+            assert!(state.company_token_coverage >= trace.threshold_min_company_token_coverage);
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use ticker_sniffer::{
+    CompanyMetadata, CompanySymbolListPreprocessor, CompanyTokenProcessorConfig, Date, Error,
+    TickerSniffer, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+};
+
+#[cfg(test)]
+mod historical_symbol_tests {
+    use super::*;
+
+    const TEXT: &str = "Facebook (FB) and Twitter (TWTR) shares fell, while Apple rose.";
+
+    fn get_ticker_sniffer_as_of(as_of_date: &str) -> TickerSniffer {
+        let config = CompanyTokenProcessorConfig::builder()
+            .as_of_date(as_of_date.parse().unwrap())
+            .build()
+            .unwrap();
+
+        TickerSniffer::new(&config, true).unwrap()
+    }
+
+    #[test]
+    fn test_date_parsing() {
+        let date: Date = "2024-02-29".parse().unwrap();
+
+        assert_eq!((date.year(), date.month(), date.day()), (2024, 2, 29));
+        assert_eq!(date.to_string(), "2024-02-29");
+        assert!(Date::new(2019, 12, 31).unwrap() < date);
+
+        for invalid_date in ["2023-02-29", "2023-13-01", "2023-1-01", "20230101", ""] {
+            assert!(
+                matches!(invalid_date.parse::<Date>(), Err(Error::ParserError(_))),
+                "Expected '{}' to be invalid",
+                invalid_date
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_valid_as_of() {
+        let company_metadata = CompanyMetadata::new("FB", Some("Facebook, Inc."), &[])
+            .with_validity(
+                Some(Date::new(2012, 5, 18).unwrap()),
+                Some(Date::new(2022, 6, 9).unwrap()),
+            );
+
+        assert!(!company_metadata.is_valid_as_of(None));
+        assert!(!company_metadata.is_valid_as_of(Some(Date::new(2012, 5, 17).unwrap())));
+        assert!(company_metadata.is_valid_as_of(Some(Date::new(2012, 5, 18).unwrap())));
+        assert!(company_metadata.is_valid_as_of(Some(Date::new(2022, 6, 8).unwrap())));
+        assert!(!company_metadata.is_valid_as_of(Some(Date::new(2022, 6, 9).unwrap())));
+    }
+
+    #[test]
+    fn test_current_ticker_symbols() {
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        let results = ticker_sniffer.extract(TEXT).unwrap();
+
+        assert_eq!(results.get("META"), Some(&1));
+        assert_eq!(results.get("AAPL"), Some(&1));
+        assert!(!results.contains_key("FB"));
+        assert!(!results.contains_key("TWTR"));
+    }
+
+    #[test]
+    fn test_historical_ticker_symbols() {
+        let ticker_sniffer = get_ticker_sniffer_as_of("2019-06-30");
+
+        let results = ticker_sniffer.extract_results(TEXT).unwrap();
+
+        let get_successor_ticker_symbol = |ticker_symbol: &str| {
+            results
+                .iter()
+                .find(|result| result.ticker_symbol == ticker_symbol)
+                .map(|result| result.successor_ticker_symbol.as_deref())
+        };
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(get_successor_ticker_symbol("FB"), Some(Some("META")));
+        assert_eq!(get_successor_ticker_symbol("TWTR"), Some(None));
+        assert_eq!(get_successor_ticker_symbol("AAPL"), Some(None));
+
+        // "META" was not listed until 2022
+        assert!(!ticker_sniffer
+            .extract("Meta Platforms")
+            .unwrap()
+            .contains_key("META"));
+
+        let results = get_ticker_sniffer_as_of("2022-08-01")
+            .extract(TEXT)
+            .unwrap();
+
+        assert!(results.contains_key("META"));
+        assert!(results.contains_key("TWTR"));
+        assert!(!results.contains_key("FB"));
+    }
+
+    #[test]
+    fn test_validity_columns() {
+        let csv_str = "\
+Symbol,Company Name,Valid From,Valid Until,Successor Symbol
+ACME,Acme Corporation,,2020-01-01,acmx
+ACMX,Acme Holdings,2020-01-01,,
+";
+
+        let company_symbol_list =
+            CompanySymbolListPreprocessor::read_company_symbol_list_from_string(csv_str).unwrap();
+
+        assert_eq!(
+            company_symbol_list,
+            vec![
+                CompanyMetadata::new("ACME", Some("Acme Corporation"), &[])
+                    .with_validity(None, Some(Date::new(2020, 1, 1).unwrap()))
+                    .with_successor_ticker_symbol("ACMX"),
+                CompanyMetadata::new("ACMX", Some("Acme Holdings"), &[])
+                    .with_validity(Some(Date::new(2020, 1, 1).unwrap()), None),
+            ]
+        );
+
+        match CompanySymbolListPreprocessor::read_company_symbol_list_from_string(
+            "Symbol,Valid From\nACME,01/01/2020\n",
+        ) {
+            Err(Error::CsvRowError { line, column, .. }) => {
+                assert_eq!(line, Some(2));
+                assert_eq!(column.as_deref(), Some("Valid From"));
+            }
+            other => panic!("Expected CsvRowError, got {:?}", other),
+        }
+    }
+}
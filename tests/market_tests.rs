@@ -0,0 +1,177 @@
+use std::str::FromStr;
+use ticker_sniffer::{
+    CompanyMetadata, CompanySymbolListOverlay, CompanySymbolListPreprocessor,
+    CompanyTokenProcessorConfig, Error, Market, TickerSniffer,
+    DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+};
+
+#[cfg(test)]
+mod market_tests {
+    use super::*;
+
+    fn get_international_overlay() -> CompanySymbolListOverlay {
+        CompanySymbolListOverlay::from_csv_str(
+            "\
+Action,Symbol,Company Name,Alternate Names,Market
+add,VOD,Vodafone Group Plc,Vodafone,London
+add,SHOP.TO,Shopify Inc.,,
+add,7203,Toyota Motor Corporation,,Tokyo
+",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_market_suffixes() {
+        assert_eq!(Market::from_ticker_symbol("VOD.L"), Market::London);
+        assert_eq!(Market::from_ticker_symbol("SHOP.TO"), Market::Toronto);
+        assert_eq!(Market::from_ticker_symbol("7203.T"), Market::Tokyo);
+        assert_eq!(Market::from_ticker_symbol("AAPL"), Market::Us);
+        assert_eq!(Market::from_ticker_symbol("BRK.B"), Market::Us);
+
+        assert_eq!(Market::London.qualify_ticker_symbol("VOD"), "VOD.L");
+        assert_eq!(Market::London.qualify_ticker_symbol("VOD.L"), "VOD.L");
+        assert_eq!(Market::Us.qualify_ticker_symbol("AAPL"), "AAPL");
+
+        for market in Market::ALL {
+            assert_eq!(Market::from_str(market.as_str()).unwrap(), market);
+        }
+
+        assert_eq!(Market::from_str("to").unwrap(), Market::Toronto);
+        assert!(matches!(
+            Market::from_str("Mars"),
+            Err(Error::ParserError(_))
+        ));
+    }
+
+    #[test]
+    fn test_exchange_prefixes() {
+        assert_eq!(Market::from_exchange_prefix("NASDAQ"), Some(Market::Us));
+        assert_eq!(
+            Market::from_exchange_prefix("nyse  american"),
+            Some(Market::Us)
+        );
+        assert_eq!(Market::from_exchange_prefix("TSX"), Some(Market::Toronto));
+        assert_eq!(
+            Market::from_exchange_prefix("TSX-V"),
+            Some(Market::TorontoVenture)
+        );
+        assert_eq!(Market::from_exchange_prefix("LSE"), Some(Market::London));
+        assert_eq!(Market::from_exchange_prefix("Note"), None);
+
+        for market in Market::ALL {
+            for exchange_prefix in market.exchange_prefixes() {
+                assert_eq!(Market::from_exchange_prefix(exchange_prefix), Some(market));
+            }
+        }
+    }
+
+    #[test]
+    fn test_company_symbol_list_market_column() {
+        let csv_str = "\
+Symbol,Company Name,Market
+VOD,Vodafone Group Plc,London
+SHOP.TO,Shopify Inc.,
+AAPL,Apple Inc.,
+";
+
+        let company_symbol_list =
+            CompanySymbolListPreprocessor::read_company_symbol_list_from_string(csv_str).unwrap();
+
+        assert_eq!(
+            company_symbol_list,
+            vec![
+                CompanyMetadata::new("VOD.L", Some("Vodafone Group Plc"), &[]),
+                CompanyMetadata::new("SHOP.TO", Some("Shopify Inc."), &[]),
+                CompanyMetadata::new("AAPL", Some("Apple Inc."), &[]),
+            ]
+        );
+        assert_eq!(company_symbol_list[0].market, Market::London);
+
+        match CompanySymbolListPreprocessor::read_company_symbol_list_from_string(
+            "Symbol,Market\nVOD,Mars\n",
+        ) {
+            Err(Error::CsvRowError { line, column, .. }) => {
+                assert_eq!(line, Some(2));
+                assert_eq!(column.as_deref(), Some("Market"));
+            }
+            other => panic!("Expected CsvRowError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_extract_market_qualified_ticker_symbols() {
+        let ticker_sniffer = TickerSniffer::with_overlay(
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+            &get_international_overlay(),
+            true,
+        )
+        .unwrap();
+
+        let results = ticker_sniffer
+            .extract_results("Shares of VOD.L, SHOP.TO and 7203.T rose, while SHOP fell.")
+            .unwrap();
+
+        let get_market = |ticker_symbol: &str| {
+            results
+                .iter()
+                .find(|result| result.ticker_symbol == ticker_symbol)
+                .map(|result| result.market)
+        };
+
+        assert_eq!(results.len(), 4);
+        assert_eq!(get_market("VOD.L"), Some(Market::London));
+        assert_eq!(get_market("SHOP.TO"), Some(Market::Toronto));
+        assert_eq!(get_market("7203.T"), Some(Market::Tokyo));
+        assert_eq!(get_market("SHOP"), Some(Market::Us));
+
+        let results = ticker_sniffer
+            .extract("Vodafone Group Plc reported earnings.")
+            .unwrap();
+
+        assert_eq!(results.get("VOD.L"), Some(&1));
+    }
+
+    #[test]
+    fn test_embedded_list_is_us_only() {
+        // Non-U.S. listings are not part of the embedded symbol list, and must be
+        // supplied through a custom symbol list or overlay
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        assert!(ticker_sniffer
+            .extract("Shares of VOD.L, SHOP.TO and 7203.T rose.")
+            .unwrap()
+            .is_empty());
+
+        let config = CompanyTokenProcessorConfig::builder()
+            .included_markets([Market::London, Market::Toronto, Market::Tokyo])
+            .build()
+            .unwrap();
+
+        let ticker_sniffer = TickerSniffer::new(&config, true).unwrap();
+
+        assert!(ticker_sniffer
+            .extract("Vodafone, Shopify and Toyota shares rose.")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_included_markets() {
+        let config = CompanyTokenProcessorConfig::builder()
+            .included_markets([Market::London])
+            .build()
+            .unwrap();
+
+        let ticker_sniffer =
+            TickerSniffer::with_overlay(&config, &get_international_overlay(), true).unwrap();
+
+        let results = ticker_sniffer
+            .extract("Apple, VOD.L, SHOP.TO and 7203.T")
+            .unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results.get("VOD.L"), Some(&1));
+    }
+}
@@ -0,0 +1,150 @@
+use ticker_sniffer::{
+    MarkupFormat, MarkupText, TickerSniffer, TickerSymbolMentionKind,
+    DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+};
+
+#[cfg(test)]
+mod markup_text_tests {
+    use super::*;
+
+    #[test]
+    fn test_html_visible_text() {
+        let html = "<html><head><title>Markets</title><style>p { color: red; }</style></head>\
+            <body><p>Shares of AT&amp;T &#38; Micro<b>soft</b></p><!-- Tesla -->\
+            <script>if (a < b) { var x = \"</p>\"; }</script><ul><li>Apple</li><li>Intel</li></ul>\
+            <p>5 &lt; 6 &unknown; &#x24;AMD</p></body></html>";
+
+        assert_eq!(
+            MarkupText::from_html(html).text(),
+            "Markets\n\nShares of AT&T & Microsoft\n\nApple\n\nIntel\n\n5 < 6 &unknown; $AMD"
+        );
+    }
+
+    #[test]
+    fn test_markdown_visible_text() {
+        let markdown = "# Apple\n\n\
+            Shares of **Microsoft** and [Tesla](https://example.com/TSLA) rose. ![Amazon](amazon.png)\n\n\
+            ```\nGOOGL = 1\n```\n\n\
+            | Company | Ticker |\n| --- | --- |\n| Nvidia | `NVDA` |\n\n\
+            - AT&amp;T\n- snake_case and \\*Intel\\*\n\n\
+            [ref]: https://example.com\n";
+
+        assert_eq!(
+            MarkupText::from_markdown(markdown).text(),
+            "Apple\n\nShares of Microsoft and Tesla rose. \n\n  Company   Ticker  \n  Nvidia   NVDA  \n\n\
+             AT&T\n\nsnake_case and *Intel*"
+        );
+    }
+
+    #[test]
+    fn test_source_ranges() {
+        let html = "<p>Shares of <b>AT&amp;T</b> rose.</p>";
+        let markup_text = MarkupText::from_html(html);
+
+        assert_eq!(markup_text.text(), "Shares of AT&T rose.");
+        assert_eq!(
+            &html[markup_text.to_source_byte_range(&(10..14))],
+            "AT&amp;T"
+        );
+        assert_eq!(markup_text.to_source_char_range(&(10..14)), 16..24);
+
+        // An empty range maps to the start of the next character
+        assert_eq!(markup_text.to_source_byte_range(&(10..10)), 16..16);
+
+        // Plain text maps to itself
+        let text = "Shares of Apple rose.";
+        let markup_text = MarkupText::new(text, MarkupFormat::PlainText);
+        assert_eq!(markup_text.text(), text);
+        assert_eq!(markup_text.to_source_byte_range(&(10..15)), 10..15);
+    }
+
+    #[test]
+    fn test_extract_markup_mentions() {
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        let html = "<p>Shares of <b>AT&amp;T</b> rose,</p><script>var MSFT = 1;</script>\
+            <p>while <a href=\"/nvda\">Nvidia</a> and NASDAQ:&nbsp;AMD&#8217;s rivals fell.</p>";
+
+        let mentions = ticker_sniffer
+            .extract_markup_mentions(html, MarkupFormat::Html)
+            .unwrap();
+
+        let summary: Vec<(&str, &str, &str, TickerSymbolMentionKind)> = mentions
+            .iter()
+            .map(|mention| {
+                (
+                    mention.ticker_symbol.as_str(),
+                    mention.text.as_str(),
+                    &html[mention.byte_range.clone()],
+                    mention.kind,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "T",
+                    "AT&T",
+                    "AT&amp;T",
+                    TickerSymbolMentionKind::CompanyName
+                ),
+                (
+                    "NVDA",
+                    "Nvidia",
+                    "Nvidia",
+                    TickerSymbolMentionKind::CompanyName
+                ),
+                (
+                    "AMD",
+                    "NASDAQ:\u{A0}AMD",
+                    "NASDAQ:&nbsp;AMD",
+                    TickerSymbolMentionKind::ExchangePrefixed
+                ),
+            ]
+        );
+
+        for mention in &mentions {
+            let char_range_text: String = html
+                .chars()
+                .skip(mention.char_range.start)
+                .take(mention.char_range.len())
+                .collect();
+            assert_eq!(char_range_text, &html[mention.byte_range.clone()]);
+        }
+    }
+
+    #[test]
+    fn test_extract_markup() {
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        let markdown = "Shares of **Microsoft** and [Tesla](https://example.com/AAPL) rose.\n\n\
+            ```\nvar GOOGL = 1;\n```\n";
+
+        let results = ticker_sniffer
+            .extract_markup(markdown, MarkupFormat::Markdown)
+            .unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results.get("MSFT"), Some(&1));
+        assert_eq!(results.get("TSLA"), Some(&1));
+
+        let results = ticker_sniffer
+            .extract_markup_results(markdown, MarkupFormat::Markdown)
+            .unwrap();
+        assert_eq!(results.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_markup_format() {
+        for format in MarkupFormat::ALL {
+            assert_eq!(format.as_str().parse::<MarkupFormat>().unwrap(), format);
+        }
+
+        assert_eq!("HTML".parse::<MarkupFormat>().unwrap(), MarkupFormat::Html);
+        assert!("pdf".parse::<MarkupFormat>().is_err());
+    }
+}
@@ -0,0 +1,67 @@
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use ticker_sniffer::{
+        CompanyTokenProcessorConfig, ExtractionTrace, TickerSniffer, TickerSymbolMention,
+        TickerSymbolResult, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+    };
+
+    #[test]
+    fn test_config_round_trip() {
+        let json = serde_json::to_string(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG).unwrap();
+        let config: CompanyTokenProcessorConfig = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            config.threshold_ratio_exact_matches,
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG.threshold_ratio_exact_matches
+        );
+        assert_eq!(
+            config.threshold_min_company_token_coverage,
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG.threshold_min_company_token_coverage
+        );
+    }
+
+    #[test]
+    fn test_results_and_mentions_round_trip() {
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        let text = "Apple Inc. (AAPL) and Microsoft";
+
+        let results = ticker_sniffer.extract_results(text).unwrap();
+        let json = serde_json::to_string(&results).unwrap();
+        let deserialized: Vec<TickerSymbolResult> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, results);
+
+        let mentions = ticker_sniffer.extract_mentions(text).unwrap();
+        let json = serde_json::to_string(&mentions).unwrap();
+        let deserialized: Vec<TickerSymbolMention> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, mentions);
+    }
+
+    #[test]
+    fn test_trace_round_trip() {
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        let trace = ticker_sniffer.explain("Apple, Microsoft and AMZN").unwrap();
+        let json = serde_json::to_string(&trace).unwrap();
+        let deserialized: ExtractionTrace = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            deserialized.ticker_symbol_frequency_map,
+            trace.ticker_symbol_frequency_map
+        );
+        assert_eq!(
+            deserialized.token_parity_states.len(),
+            trace.token_parity_states.len()
+        );
+        assert_eq!(
+            deserialized.top_range_states.len(),
+            trace.top_range_states.len()
+        );
+        assert_eq!(
+            deserialized.ticker_symbol_traces[0].outcome,
+            trace.ticker_symbol_traces[0].outcome
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use ticker_sniffer::{
+    get_company_metadata, normalize_ticker_symbol, CompanySymbolListPreprocessor, Market,
+    TickerSniffer, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+};
+
+#[cfg(test)]
+mod share_class_tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_ticker_symbol() {
+        let expected = [
+            ("BRK.B", "BRK-B"),
+            ("BRK/B", "BRK-B"),
+            ("BRK B", "BRK-B"),
+            ("BRK-B", "BRK-B"),
+            (" brk.b ", "BRK-B"),
+            ("ABR^D", "ABR-PD"),
+            ("ABR.PRD", "ABR-PD"),
+            ("ABR-PR", "ABR-PR"),
+            ("ACHR.WS", "ACHR-WT"),
+            ("AACT.U", "AACT-UN"),
+            ("AAPL", "AAPL"),
+            ("VOD.L", "VOD.L"),
+            ("7203.T", "7203.T"),
+        ];
+
+        for (ticker_symbol, normalized_ticker_symbol) in expected {
+            assert_eq!(
+                normalize_ticker_symbol(ticker_symbol),
+                normalized_ticker_symbol,
+                "Unexpected normalization of {}",
+                ticker_symbol
+            );
+        }
+    }
+
+    #[test]
+    fn test_normalize_embedded_ticker_symbols_is_idempotent() {
+        let company_symbol_list =
+            CompanySymbolListPreprocessor::read_company_symbol_list_from_path(
+                "data/company_symbol_list.csv",
+            )
+            .unwrap();
+
+        for company_metadata in &company_symbol_list {
+            assert_eq!(
+                normalize_ticker_symbol(&company_metadata.ticker_symbol),
+                company_metadata.ticker_symbol
+            );
+        }
+    }
+
+    #[test]
+    fn test_extract_share_class_variants() {
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        for text in [
+            "Shares of BRK.B rose.",
+            "Shares of BRK/B rose.",
+            "Shares of BRK B rose.",
+            "Shares of BRK-B rose.",
+            "Shares of BRK.B's parent rose.",
+        ] {
+            let results = ticker_sniffer.extract(text).unwrap();
+
+            assert_eq!(results.len(), 1, "Unexpected results for '{}'", text);
+            assert_eq!(
+                results.get("BRK-B"),
+                Some(&1),
+                "Missing BRK-B in '{}'",
+                text
+            );
+        }
+
+        let results = ticker_sniffer.extract("Shares of BRK.A and BRK.B").unwrap();
+        assert_eq!(results.get("BRK-A"), Some(&1));
+        assert_eq!(results.get("BRK-B"), Some(&1));
+    }
+
+    #[test]
+    fn test_share_class_is_preferred_over_market_suffix() {
+        // ".V" is both the TSX Venture suffix and a share class, and "MKC-V" is a
+        // U.S. share class in the embedded list
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        for text in ["Shares of MKC.V rose.", "Shares of MKC-V rose."] {
+            let results = ticker_sniffer.extract(text).unwrap();
+
+            assert_eq!(results.len(), 1, "Unexpected results for '{}'", text);
+            assert_eq!(
+                results.get("MKC-V"),
+                Some(&1),
+                "Missing MKC-V in '{}'",
+                text
+            );
+        }
+
+        let company_metadata = get_company_metadata("MKC.V").unwrap().unwrap();
+        assert_eq!(company_metadata.ticker_symbol, "MKC-V");
+        assert_eq!(company_metadata.market, Market::Us);
+
+        // Without a matching share class, the suffix remains a market suffix
+        assert_eq!(normalize_ticker_symbol("MKC.V"), "MKC.V");
+        assert!(get_company_metadata("ZZZZ.V").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_extract_share_class_byte_ranges() {
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        let text = "Shares of BRK B rose.";
+        let mentions = ticker_sniffer.extract_mentions(text).unwrap();
+
+        assert_eq!(mentions.len(), 1);
+        assert_eq!(mentions[0].ticker_symbol, "BRK-B");
+        assert_eq!(&text[mentions[0].byte_range.clone()], "BRK B");
+        assert_eq!(mentions[0].text, "BRK B");
+    }
+
+    #[test]
+    fn test_share_class_does_not_merge_distinct_symbols() {
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        let results = ticker_sniffer.extract("Shares of T-PC rose.").unwrap();
+        assert_eq!(results.get("T-PC"), Some(&1));
+        assert_eq!(results.get("TPC"), None);
+
+        let results = ticker_sniffer.extract("Shares of TPC rose.").unwrap();
+        assert_eq!(results.get("TPC"), Some(&1));
+        assert_eq!(results.get("T-PC"), None);
+
+        // Lowercase text is not mistaken for a share class in case sensitive mode
+        let results = ticker_sniffer.extract("the brk.b shares").unwrap();
+        assert_eq!(results.get("BRK-B"), None);
+    }
+
+    #[test]
+    fn test_get_company_metadata_with_share_class_variants() {
+        for ticker_symbol in ["BRK-B", "BRK.B", "brk/b", "BRK B"] {
+            let company_metadata = get_company_metadata(ticker_symbol).unwrap().unwrap();
+
+            assert_eq!(company_metadata.ticker_symbol, "BRK-B");
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use ticker_sniffer::{
+    CompanyMetadata, CompanySymbolListPreprocessor, CompanyTokenProcessorConfig, Error,
+    StopWordPreset, StopWords, TickerSniffer, Tokenizer, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+};
+
+#[cfg(test)]
+mod stop_words_tests {
+    use super::*;
+
+    #[test]
+    fn test_stop_word_presets() {
+        let tokenizer = Tokenizer::text_doc_parser(true)
+            .with_stop_words(&StopWords::new(StopWordPreset::German));
+
+        assert_eq!(
+            tokenizer.tokenize("Die Deutsche Bank und die Commerzbank"),
+            vec!["DEUTSCHE", "BANK", "COMMERZBANK"]
+        );
+
+        // English stop words are kept when another preset is selected
+        assert_eq!(tokenizer.tokenize("The Bank"), vec!["THE", "BANK"]);
+
+        for preset in StopWordPreset::ALL {
+            assert_eq!(preset.as_str().parse::<StopWordPreset>().unwrap(), preset);
+        }
+        assert!("Klingon".parse::<StopWordPreset>().is_err());
+    }
+
+    #[test]
+    fn test_added_and_removed_stop_words() {
+        let stop_words = StopWords::default()
+            .with_added_words(["Holdings"])
+            .with_removed_words(["ALL"]);
+
+        let tokenizer = Tokenizer::text_doc_parser(true).with_stop_words(&stop_words);
+
+        assert_eq!(
+            tokenizer.tokenize("All of the Holdings of Acme"),
+            vec!["ALL", "ACME"]
+        );
+
+        let tokenizer =
+            Tokenizer::text_doc_parser(false).with_stop_words(&StopWords::from_words(["acme"]));
+
+        assert_eq!(
+            tokenizer.tokenize("All of the Holdings of Acme"),
+            vec!["ALL", "OF", "THE", "HOLDINGS", "OF"]
+        );
+    }
+
+    #[test]
+    fn test_company_names_are_tokenized_with_stop_words() {
+        let company_symbol_list = vec![CompanyMetadata::new("ACME", Some("Acme Widgets"), &[])];
+
+        let ticker_sniffer = TickerSniffer::with_company_symbol_list(
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+            &company_symbol_list,
+            true,
+        )
+        .unwrap();
+        assert!(ticker_sniffer.extract("Acme rose").unwrap().is_empty());
+
+        // The filtered word is also removed from the company name, so "Acme" alone
+        // covers the whole company name
+        let config = CompanyTokenProcessorConfig::builder()
+            .add_stop_words(["widgets"])
+            .build()
+            .unwrap();
+
+        let ticker_sniffer =
+            TickerSniffer::with_company_symbol_list(&config, &company_symbol_list, true).unwrap();
+        assert_eq!(
+            ticker_sniffer.extract("Acme rose").unwrap().get("ACME"),
+            Some(&1)
+        );
+    }
+
+    #[test]
+    fn test_embedded_symbol_index_is_rebuilt_for_stop_words() {
+        let config = CompanyTokenProcessorConfig::builder()
+            .remove_stop_words(["the"])
+            .build()
+            .unwrap();
+
+        let company_symbol_list =
+            CompanySymbolListPreprocessor::read_company_symbol_list_from_path(
+                "data/company_symbol_list.csv",
+            )
+            .unwrap();
+
+        let embedded_ticker_sniffer = TickerSniffer::new(&config, true).unwrap();
+        let ticker_sniffer =
+            TickerSniffer::with_company_symbol_list(&config, &company_symbol_list, true).unwrap();
+
+        let text = "The Walt Disney Company and The Home Depot reported earnings.";
+        assert_eq!(
+            embedded_ticker_sniffer.extract_results(text).unwrap(),
+            ticker_sniffer.extract_results(text).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_stop_words() {
+        for stop_words in [
+            StopWords::default().with_added_words([""]),
+            StopWords::default().with_removed_words(["two words"]),
+        ] {
+            let result = CompanyTokenProcessorConfig::builder()
+                .stop_words(stop_words)
+                .build();
+
+            assert!(matches!(result, Err(Error::ConfigError(_))));
+        }
+    }
+}
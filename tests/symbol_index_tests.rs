@@ -0,0 +1,179 @@
+use std::fs;
+use ticker_sniffer::{
+    CompanyMetadata, CompanySymbolListPreprocessor, CompanyTokenProcessorConfig, Date, Error,
+    SymbolIndex, TickerSniffer, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+};
+
+#[cfg(test)]
+mod symbol_index_tests {
+    use super::*;
+
+    fn get_company_symbol_list() -> Vec<CompanyMetadata> {
+        CompanySymbolListPreprocessor::read_company_symbol_list_from_path(
+            "data/company_symbol_list.csv",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_embedded_symbol_index_is_up_to_date() {
+        let symbol_index = SymbolIndex::new(get_company_symbol_list()).unwrap();
+
+        assert!(
+            symbol_index.to_bytes() == fs::read("data/company_symbol_index.bin").unwrap(),
+            "The symbol index is out of date. Run `cargo run -p dev --bin build_symbol_index`."
+        );
+    }
+
+    #[test]
+    fn test_symbol_index_round_trip() {
+        let company_symbol_list = vec![
+            CompanyMetadata::new(
+                "IBM",
+                Some("International Business Machines"),
+                &["Big Blue"],
+            ),
+            CompanyMetadata::new("BRK-B", Some("Berkshire Hathaway Inc."), &["Berkshire B"]),
+            CompanyMetadata::new("VOD.L", Some("Vodafone Group Plc"), &[]),
+            CompanyMetadata::new("FB", Some("Facebook, Inc."), &["Facebook"])
+                .with_validity(None, Some(Date::new(2022, 6, 9).unwrap()))
+                .with_successor_ticker_symbol("META"),
+        ];
+
+        let symbol_index = SymbolIndex::new(company_symbol_list.clone()).unwrap();
+        let bytes = symbol_index.to_bytes();
+
+        let symbol_index = SymbolIndex::from_bytes(&bytes).unwrap();
+        assert_eq!(symbol_index.company_symbol_list(), &company_symbol_list);
+        assert_eq!(symbol_index.to_bytes(), bytes);
+
+        assert_eq!(
+            SymbolIndex::read_company_symbol_list(&bytes).unwrap(),
+            company_symbol_list
+        );
+    }
+
+    #[test]
+    fn test_symbol_index_derives_company_name_tokens() {
+        // The token IDs of company names are derived from the words of the names,
+        // including words which are stop words or contain punctuation
+        let company_symbol_list = vec![
+            CompanyMetadata::new("DIS", Some("The Walt Disney Company"), &["Disney"]),
+            CompanyMetadata::new("T", Some("AT&T Inc."), &["AT&T"]),
+            CompanyMetadata::new("JNJ", Some("Johnson & Johnson"), &["J&J"]),
+            CompanyMetadata::new("KO", Some("The Coca-Cola Company"), &["Coca-Cola"]),
+        ];
+
+        let bytes = SymbolIndex::new(company_symbol_list.clone())
+            .unwrap()
+            .to_bytes();
+        let symbol_index = SymbolIndex::from_bytes(&bytes).unwrap();
+
+        let indexed_ticker_sniffer = TickerSniffer::with_symbol_index(
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+            symbol_index,
+            true,
+        )
+        .unwrap();
+        let ticker_sniffer = TickerSniffer::with_company_symbol_list(
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+            &company_symbol_list,
+            true,
+        )
+        .unwrap();
+
+        let text = "The Walt Disney Company, AT&T, Johnson & Johnson and Coca-Cola rose.";
+        let results = indexed_ticker_sniffer.extract_results(text).unwrap();
+
+        assert_eq!(results, ticker_sniffer.extract_results(text).unwrap());
+        assert!(!results.is_empty());
+    }
+
+    #[test]
+    fn test_symbol_index_matches_company_symbol_list() {
+        let texts = [
+            "Apple and Microsoft reported earnings, while AMZN fell.",
+            "Berkshire Hathaway (BRK.B) and Walmart's shares rose.",
+            "The SPDR Dow Jones Industrial Average ETF tracks the Dow.",
+        ];
+
+        let company_symbol_list = get_company_symbol_list();
+
+        for is_case_sensitive in [true, false] {
+            let embedded_ticker_sniffer =
+                TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, is_case_sensitive)
+                    .unwrap();
+            let ticker_sniffer = TickerSniffer::with_company_symbol_list(
+                DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+                &company_symbol_list,
+                is_case_sensitive,
+            )
+            .unwrap();
+
+            for text in texts {
+                assert_eq!(
+                    embedded_ticker_sniffer.extract_results(text).unwrap(),
+                    ticker_sniffer.extract_results(text).unwrap(),
+                    "Results differ for '{}'",
+                    text
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_symbol_index_with_different_entry_filters() {
+        // Configurations which include different entries rebuild the token maps
+        // from the company symbol list stored in the index
+        let config = CompanyTokenProcessorConfig::builder()
+            .as_of_date(Date::new(2019, 6, 30).unwrap())
+            .build()
+            .unwrap();
+
+        let ticker_sniffer = TickerSniffer::new(&config, true).unwrap();
+
+        let results = ticker_sniffer.extract("Facebook and Twitter").unwrap();
+        assert_eq!(results.get("FB"), Some(&1));
+        assert_eq!(results.get("TWTR"), Some(&1));
+    }
+
+    #[test]
+    fn test_invalid_symbol_index() {
+        assert!(matches!(
+            SymbolIndex::from_bytes(b"not an index"),
+            Err(Error::SymbolIndexError(_))
+        ));
+
+        let bytes = SymbolIndex::new(vec![CompanyMetadata::new("IBM", None, &["Big Blue"])])
+            .unwrap()
+            .to_bytes();
+
+        assert!(matches!(
+            SymbolIndex::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(Error::SymbolIndexError(_))
+        ));
+
+        // A huge length prefix is rejected rather than preallocated
+        let mut huge_length_bytes = b"TSIX\x02".to_vec();
+        huge_length_bytes.extend_from_slice(&[0xff; 9]);
+        huge_length_bytes.push(0x7f);
+        assert!(matches!(
+            SymbolIndex::from_bytes(&huge_length_bytes),
+            Err(Error::SymbolIndexError(_))
+        ));
+
+        let mut huge_length_bytes = b"TSIX\x02".to_vec();
+        huge_length_bytes.extend_from_slice(&[0x80, 0x80, 0x80, 0x80, 0x10]);
+        assert!(matches!(
+            SymbolIndex::read_company_symbol_list(&huge_length_bytes),
+            Err(Error::SymbolIndexError(_))
+        ));
+
+        let mut unsupported_bytes = bytes.clone();
+        unsupported_bytes[4] += 1;
+        assert!(matches!(
+            SymbolIndex::from_bytes(&unsupported_bytes),
+            Err(Error::SymbolIndexError(_))
+        ));
+    }
+}
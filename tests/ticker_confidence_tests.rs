@@ -0,0 +1,79 @@
+use ticker_sniffer::{extract_ticker_mentions_from_text, extract_ticker_results_from_text};
+
+#[cfg(test)]
+mod ticker_confidence_tests {
+    use super::*;
+
+    fn get_confidence(text: &str, ticker_symbol: &str) -> f32 {
+        extract_ticker_results_from_text(text, true)
+            .unwrap()
+            .into_iter()
+            .find(|result| result.ticker_symbol == ticker_symbol)
+            .unwrap_or_else(|| panic!("Expected {} in results for {:?}", ticker_symbol, text))
+            .confidence
+    }
+
+    #[test]
+    fn test_confidences_are_normalized() {
+        let text = "Apple Inc. (AAPL), Microsoft and Berkshire Hathaway. Apple, Apple, Apple.";
+
+        for result in extract_ticker_results_from_text(text, true).unwrap() {
+            assert!(
+                result.confidence > 0.0 && result.confidence <= 1.0,
+                "Unexpected confidence for {:?}",
+                result
+            );
+        }
+
+        for mention in extract_ticker_mentions_from_text(text, true).unwrap() {
+            assert!(
+                mention.confidence > 0.0 && mention.confidence <= 1.0,
+                "Unexpected confidence for {:?}",
+                mention
+            );
+        }
+    }
+
+    #[test]
+    fn test_repeated_explicit_mentions_increase_confidence() {
+        let single_ambiguous = get_confidence("Apple", "AAPL");
+        let repeated_explicit = get_confidence(
+            "Apple Inc. (AAPL) rose. Apple Inc. (AAPL) rose again.",
+            "AAPL",
+        );
+
+        assert!(
+            repeated_explicit > single_ambiguous,
+            "Expected {} > {}",
+            repeated_explicit,
+            single_ambiguous
+        );
+    }
+
+    #[test]
+    fn test_ambiguous_mentions_reduce_confidence() {
+        // "Berkshire Hathaway" is shared by multiple share classes
+        let ambiguous = get_confidence("Berkshire Hathaway", "BRK-A");
+        let unambiguous = get_confidence("Microsoft", "MSFT");
+
+        assert!(
+            ambiguous < unambiguous,
+            "Expected {} < {}",
+            ambiguous,
+            unambiguous
+        );
+    }
+
+    #[test]
+    fn test_results_ordered_by_confidence() {
+        let text = "Microsoft, Microsoft Corporation, and Microsoft again. Also Walmart.";
+        let results = extract_ticker_results_from_text(text, true).unwrap();
+
+        assert_eq!(results[0].ticker_symbol, "MSFT");
+        assert_eq!(results[0].mention_count, 3);
+
+        for pair in results.windows(2) {
+            assert!(pair[0].confidence >= pair[1].confidence);
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use ticker_sniffer::{extract_ticker_mentions_from_text, TickerSymbolMentionKind};
+
+#[cfg(test)]
+mod ticker_mention_tests {
+    use super::*;
+
+    #[test]
+    fn test_mention_byte_ranges_match_original_text() {
+        let text = "Walmart's shares rose, while Berkshire Hathaway,\nand Apple were flat.";
+        let mentions = extract_ticker_mentions_from_text(text, true).unwrap();
+
+        assert!(!mentions.is_empty());
+
+        for mention in &mentions {
+            assert_eq!(&text[mention.byte_range.clone()], mention.text);
+        }
+
+        let walmart = mentions
+            .iter()
+            .find(|mention| mention.ticker_symbol == "WMT")
+            .expect("Expected a WMT mention");
+        assert_eq!(walmart.text, "Walmart");
+        assert_eq!(walmart.kind, TickerSymbolMentionKind::CompanyName);
+
+        let berkshire = mentions
+            .iter()
+            .find(|mention| mention.text.starts_with("Berkshire"))
+            .expect("Expected a Berkshire mention");
+        assert_eq!(berkshire.text, "Berkshire Hathaway");
+    }
+
+    #[test]
+    fn test_mentions_include_bare_ticker_symbols() {
+        let text = "AAPL, MSFT and AMZN all traded higher.";
+        let mentions = extract_ticker_mentions_from_text(text, true).unwrap();
+
+        let ticker_symbols: Vec<&str> = mentions
+            .iter()
+            .map(|mention| mention.ticker_symbol.as_str())
+            .collect();
+        assert_eq!(ticker_symbols, vec!["AAPL", "MSFT", "AMZN"]);
+
+        assert!(mentions
+            .iter()
+            .all(|mention| mention.kind == TickerSymbolMentionKind::TickerSymbol));
+        assert_eq!(mentions[2].byte_range, 15..19);
+    }
+
+    #[test]
+    fn test_mentions_are_ordered_by_position() {
+        let text = "Amazon and Walmart compete, but Amazon leads.";
+        let mentions = extract_ticker_mentions_from_text(text, true).unwrap();
+
+        let starts: Vec<usize> = mentions
+            .iter()
+            .map(|mention| mention.byte_range.start)
+            .collect();
+        let mut sorted_starts = starts.clone();
+        sorted_starts.sort();
+        assert_eq!(starts, sorted_starts);
+
+        let amzn_mentions = mentions
+            .iter()
+            .filter(|mention| mention.ticker_symbol == "AMZN")
+            .count();
+        assert_eq!(amzn_mentions, 2);
+    }
+
+    #[test]
+    fn test_mention_char_ranges() {
+        let text = "“Disney” and “Microsoft” both reported earnings.";
+        let mentions = extract_ticker_mentions_from_text(text, true).unwrap();
+
+        let microsoft = mentions
+            .iter()
+            .find(|mention| mention.ticker_symbol == "MSFT")
+            .expect("Expected an MSFT mention");
+
+        assert_eq!(microsoft.text, "Microsoft");
+        assert_eq!(microsoft.char_range, 14..23);
+        assert_eq!(
+            text.chars()
+                .skip(microsoft.char_range.start)
+                .take(microsoft.char_range.len())
+                .collect::<String>(),
+            "Microsoft"
+        );
+    }
+
+    #[test]
+    fn test_mentions_empty_text() {
+        let mentions = extract_ticker_mentions_from_text("", true).unwrap();
+        assert!(mentions.is_empty());
+    }
+}
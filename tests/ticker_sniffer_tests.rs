@@ -0,0 +1,89 @@
+use std::sync::Arc;
+use std::thread;
+use ticker_sniffer::{
+    extract_tickers_from_text, CompanyMetadata, CompanySymbolList, TickerSniffer,
+    DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+};
+
+#[cfg(test)]
+mod ticker_sniffer_tests {
+    use super::*;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_ticker_sniffer_is_send_and_sync() {
+        assert_send_sync::<TickerSniffer>();
+    }
+
+    #[test]
+    fn test_ticker_sniffer_matches_free_function() {
+        let ticker_sniffer = TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true)
+            .expect("Failed to build TickerSniffer");
+
+        let texts = [
+            "Berkshire Hathaway and Apple",
+            "AAPL, MSFT and AMZN all traded higher.",
+            "Walmart's shares rose, while Amazon's fell.",
+            "",
+        ];
+
+        for text in texts {
+            assert_eq!(
+                ticker_sniffer.extract(text).unwrap(),
+                extract_tickers_from_text(text, true).unwrap(),
+                "Mismatched results for {:?}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_ticker_sniffer_shared_across_threads() {
+        let ticker_sniffer = Arc::new(
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true)
+                .expect("Failed to build TickerSniffer"),
+        );
+
+        let handles: Vec<_> = ["Apple", "Microsoft", "Amazon", "Walmart"]
+            .into_iter()
+            .map(|text| {
+                let ticker_sniffer = Arc::clone(&ticker_sniffer);
+                thread::spawn(move || ticker_sniffer.extract(text).unwrap())
+            })
+            .collect();
+
+        let results: Vec<_> = handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect();
+
+        assert!(results[0].contains_key("AAPL"));
+        assert!(results[1].contains_key("MSFT"));
+        assert!(results[2].contains_key("AMZN"));
+        assert!(results[3].contains_key("WMT"));
+    }
+
+    #[test]
+    fn test_ticker_sniffer_with_company_symbol_list() {
+        let company_symbol_list: CompanySymbolList = vec![
+            CompanyMetadata::new("ACME", Some("Acme Corporation"), &["Acme"]),
+            CompanyMetadata::new("GLBX", Some("Globex Corporation"), &["Globex"]),
+        ];
+
+        let ticker_sniffer = TickerSniffer::with_company_symbol_list(
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+            &company_symbol_list,
+            true,
+        )
+        .expect("Failed to build TickerSniffer");
+
+        let results = ticker_sniffer
+            .extract("Acme is suing Globex Corporation, but not Apple.")
+            .unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results.get("ACME"), Some(&1));
+        assert_eq!(results.get("GLBX"), Some(&1));
+    }
+}
@@ -0,0 +1,106 @@
+use ticker_sniffer::{
+    CompanyMetadata, CompanyTokenMapper, TickerSniffer, TokenSequenceCollision,
+    DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+};
+
+#[cfg(test)]
+mod token_sequence_collision_tests {
+    use super::*;
+
+    fn get_company_token_mapper() -> CompanyTokenMapper {
+        let company_symbol_list = vec![
+            CompanyMetadata::new("AAPL", Some("Apple Inc."), &["Apple"]),
+            CompanyMetadata::new(
+                "APLE",
+                Some("Apple Hospitality REIT, Inc."),
+                &["Apple Hospitality"],
+            ),
+            CompanyMetadata::new(
+                "FBGRX",
+                Some("Fidelity Blue Chip Growth Fund"),
+                &["Fidelity Blue Chip"],
+            ),
+            CompanyMetadata::new(
+                "FBCVX",
+                Some("Fidelity Blue Chip Value"),
+                &["Fidelity Blue Chip"],
+            ),
+            CompanyMetadata::new("FBCG", Some("Fidelity Blue Chip Growth ETF"), &[]),
+        ];
+
+        CompanyTokenMapper::new(&company_symbol_list, true).unwrap()
+    }
+
+    fn find_collision<'a>(
+        collisions: &'a [TokenSequenceCollision],
+        text: &str,
+    ) -> Option<&'a TokenSequenceCollision> {
+        collisions
+            .iter()
+            .find(|collision| collision.tokens.join(" ") == text)
+    }
+
+    #[test]
+    fn test_collect_token_sequence_collisions() {
+        let company_token_mapper = get_company_token_mapper();
+
+        let collisions = company_token_mapper
+            .collect_token_sequence_collisions(0.6)
+            .unwrap();
+
+        let collision = find_collision(&collisions, "FIDELITY BLUE CHIP").unwrap();
+        let ticker_symbols: Vec<&str> = collision
+            .matches
+            .iter()
+            .map(|collision_match| collision_match.ticker_symbol.as_str())
+            .collect();
+        assert_eq!(ticker_symbols, vec!["FBCVX", "FBGRX", "FBCG"]);
+        assert_eq!(collision.matches[2].company_token_coverage, 0.6);
+        assert_eq!(
+            collision.matches[2].company_tokens.join(" "),
+            "FIDELITY BLUE CHIP GROWTH ETF"
+        );
+
+        // Competing coverage (1.0 + 0.6) over 3 tokens
+        assert!((collision.ambiguity_score - 1.6 / 3.0).abs() < 1e-6);
+
+        // "Apple" only covers half of "Apple Hospitality"
+        assert!(find_collision(&collisions, "APPLE").is_none());
+
+        // Sequences reachable from a single ticker symbol are not collisions
+        assert!(find_collision(&collisions, "FIDELITY BLUE CHIP VALUE").is_none());
+    }
+
+    #[test]
+    fn test_collect_token_sequence_collisions_with_lower_coverage() {
+        let company_token_mapper = get_company_token_mapper();
+
+        let collisions = company_token_mapper
+            .collect_token_sequence_collisions(0.5)
+            .unwrap();
+
+        let collision = find_collision(&collisions, "APPLE").unwrap();
+        assert_eq!(collision.matches.len(), 2);
+        assert_eq!(collision.matches[0].ticker_symbol, "AAPL");
+        assert_eq!(collision.matches[1].ticker_symbol, "APLE");
+        assert_eq!(collision.matches[1].company_token_coverage, 0.5);
+
+        assert_eq!(collision.to_tsv_row(), "0.5000\tAPPLE\tAAPL:1.00,APLE:0.50");
+    }
+
+    #[test]
+    fn test_collisions_are_ranked_by_ambiguity() {
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        let collisions = ticker_sniffer.collect_token_sequence_collisions().unwrap();
+
+        assert!(!collisions.is_empty());
+        assert!(collisions
+            .windows(2)
+            .all(|pair| pair[0].ambiguity_score >= pair[1].ambiguity_score));
+        assert!(collisions
+            .iter()
+            .all(|collision| collision.matches.len() > 1));
+    }
+}
@@ -1,4 +1,4 @@
-use ticker_sniffer::{Token, Tokenizer};
+use ticker_sniffer::{Market, Token, Tokenizer};
 
 #[cfg(test)]
 mod text_doc_tokenizer_tests {
@@ -213,6 +213,23 @@ mod ticker_symbol_tokenizer_tests {
         assert_eq!(tokens, vec!["AAPL", "MSFT", "TSLA", "BRKA"]);
     }
 
+    #[test]
+    fn test_ticker_tokenize_with_market_suffixes() {
+        let tokenizer = Tokenizer::ticker_symbol_parser(true).with_markets(&[
+            Market::London,
+            Market::Toronto,
+            Market::Tokyo,
+        ]);
+
+        let text = "(VOD.L) SHOP.TO's 7203.T. Vod.L U.S. BRK.B";
+        let tokens = tokenizer.tokenize(text);
+        assert_eq!(tokens, vec!["VOD.L", "SHOP.TO", "7203.T", "US", "BRKB"]);
+
+        // Suffixes of markets which are not configured are merged as before
+        let tokenizer = Tokenizer::ticker_symbol_parser(true);
+        assert_eq!(tokenizer.tokenize("VOD.L"), vec!["VODL"]);
+    }
+
     #[test]
     fn test_ticker_tokenize_empty_string() {
         let tokenizer = Tokenizer::ticker_symbol_parser(true);
@@ -222,3 +239,80 @@ mod ticker_symbol_tokenizer_tests {
         assert_eq!(tokens, Vec::<&str>::new());
     }
 }
+
+#[cfg(test)]
+mod token_span_tests {
+    use super::*;
+
+    #[test]
+    fn test_spans_match_tokenize() {
+        let tokenizer = Tokenizer::text_doc_parser(true);
+
+        let text = "Alphabet, Inc. (Google), Tesla's products, and Amazon's dominance.";
+        let spans = tokenizer.tokenize_with_spans(text);
+        let tokens: Vec<Token> = spans.into_iter().map(|span| span.token).collect();
+        assert_eq!(tokens, tokenizer.tokenize(text));
+    }
+
+    #[test]
+    fn test_spans_exclude_punctuation_and_possessives() {
+        let tokenizer = Tokenizer::text_doc_parser(true);
+
+        let text = "(Google), Tesla's products";
+        let spans = tokenizer.tokenize_with_spans(text);
+
+        assert_eq!(spans[0].token, "GOOGLE");
+        assert_eq!(spans[0].byte_range, 1..7);
+        assert_eq!(spans[0].surface, "Google");
+
+        assert_eq!(spans[1].token, "TESLA");
+        assert_eq!(spans[1].byte_range, 10..15);
+        assert_eq!(spans[1].surface, "Tesla");
+    }
+
+    #[test]
+    fn test_spans_across_hyphenated_line_breaks() {
+        let tokenizer = Tokenizer::text_doc_parser(true);
+
+        let text = "Wal-mart and Deve-\nlopment";
+        let spans = tokenizer.tokenize_with_spans(text);
+
+        assert_eq!(spans[0].token, "WALMART");
+        assert_eq!(spans[0].surface, "Wal-mart");
+
+        assert_eq!(spans[1].token, "DEVELOPMENT");
+        assert_eq!(spans[1].byte_range, 13..26);
+        assert_eq!(spans[1].surface, "Deve-\nlopment");
+    }
+
+    #[test]
+    fn test_spans_with_multibyte_characters() {
+        let tokenizer = Tokenizer::text_doc_parser(true);
+
+        let text = "“Nestlé” and Apple";
+        let spans = tokenizer.tokenize_with_spans(text);
+
+        assert_eq!(spans[0].token, "NESTLÉ");
+        assert_eq!(spans[0].byte_range, 3..10);
+        assert_eq!(spans[0].char_range, 1..7);
+        assert_eq!(spans[0].surface, "Nestlé");
+
+        assert_eq!(spans[1].token, "APPLE");
+        assert_eq!(spans[1].byte_range, 18..23);
+        assert_eq!(spans[1].char_range, 13..18);
+    }
+
+    #[test]
+    fn test_verbatim_spans() {
+        let tokenizer = Tokenizer::verbatim_doc_parser();
+
+        let text = " BRK.A  brk-b ";
+        let spans = tokenizer.tokenize_with_spans(text);
+
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0].token, "BRK.A");
+        assert_eq!(spans[0].byte_range, 1..6);
+        assert_eq!(spans[1].token, "brk-b");
+        assert_eq!(spans[1].byte_range, 8..13);
+    }
+}
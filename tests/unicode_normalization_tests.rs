@@ -0,0 +1,164 @@
+use ticker_sniffer::{
+    CompanyMetadata, CompanyTokenProcessorConfig, StopWordPreset, StopWords, TickerSniffer,
+    Tokenizer, DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+};
+
+#[cfg(test)]
+mod unicode_normalization_tests {
+    use super::*;
+
+    fn get_company_symbol_list() -> Vec<CompanyMetadata> {
+        vec![
+            CompanyMetadata::new("LRLCY", Some("L’Oréal S.A."), &["L'Oréal"]),
+            CompanyMetadata::new("NSRGY", Some("Nestlé S.A."), &["Nestlé"]),
+            CompanyMetadata::new("KO", Some("Coca-Cola Company"), &["Coca-Cola"]),
+        ]
+    }
+
+    #[test]
+    fn test_typographic_variants() {
+        let tokenizer = Tokenizer::text_doc_parser(false);
+
+        for (text, expected) in [
+            ("Intel’s shares", vec!["INTEL", "SHARES"]),
+            ("Micro\u{AD}soft", vec!["MICROSOFT"]),
+            (
+                "Coca\u{2011}Cola and Coca–Cola",
+                vec!["COCACOLA", "COCACOLA"],
+            ),
+            ("Apple—Microsoft", vec!["APPLE", "MICROSOFT"]),
+            ("Apple\u{00A0}Inc.", vec!["APPLE", "INC"]),
+            ("ＡＡＰＬ", vec!["AAPL"]),
+            ("Straße", vec!["STRASSE"]),
+        ] {
+            assert_eq!(tokenizer.tokenize(text), expected, "{}", text);
+        }
+
+        // Precomposed and decomposed accents produce the same token
+        assert_eq!(
+            tokenizer.tokenize("Nestl\u{E9}"),
+            tokenizer.tokenize("Nestle\u{301}")
+        );
+    }
+
+    #[test]
+    fn test_diacritic_folding() {
+        let tokenizer = Tokenizer::text_doc_parser(false);
+        assert_eq!(tokenizer.tokenize("L’Oréal"), vec!["LORÉAL"]);
+
+        let tokenizer = tokenizer.with_diacritic_folding(true);
+        assert_eq!(tokenizer.tokenize("L’Oréal"), vec!["LOREAL"]);
+        assert_eq!(tokenizer.tokenize("Nestle\u{301}"), vec!["NESTLE"]);
+    }
+
+    #[test]
+    fn test_folded_stop_words() {
+        // Accented stop words are folded like the tokens they are compared
+        // against, regardless of the order the tokenizer is configured in
+        for (preset, text) in [
+            (StopWordPreset::French, "été même Société"),
+            (StopWordPreset::German, "für über Société"),
+        ] {
+            let stop_words = StopWords::new(preset);
+
+            let tokenizer = Tokenizer::text_doc_parser(false)
+                .with_stop_words(&stop_words)
+                .with_diacritic_folding(true);
+            assert_eq!(tokenizer.tokenize(text), vec!["SOCIETE"], "{}", text);
+
+            let tokenizer = Tokenizer::text_doc_parser(false)
+                .with_diacritic_folding(true)
+                .with_stop_words(&stop_words);
+            assert_eq!(tokenizer.tokenize(text), vec!["SOCIETE"], "{}", text);
+        }
+
+        // Company names are filtered with the same folded stop words
+        let config = CompanyTokenProcessorConfig::builder()
+            .stop_word_preset(StopWordPreset::German)
+            .fold_diacritics(true)
+            .build()
+            .unwrap();
+        let company_symbol_list = vec![CompanyMetadata::new(
+            "MURGY",
+            Some("Münchener Rück für Versicherungen"),
+            &[],
+        )];
+        let ticker_sniffer =
+            TickerSniffer::with_company_symbol_list(&config, &company_symbol_list, true).unwrap();
+
+        let results = ticker_sniffer
+            .extract("Munchener Ruck fur Versicherungen stieg")
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results.get("MURGY"), Some(&1));
+    }
+
+    #[test]
+    fn test_company_names_are_normalized_like_documents() {
+        let company_symbol_list = get_company_symbol_list();
+
+        let ticker_sniffer = TickerSniffer::with_company_symbol_list(
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+            &company_symbol_list,
+            true,
+        )
+        .unwrap();
+
+        let results = ticker_sniffer
+            .extract("L'Oréal, Nestle\u{301} and Coca\u{2011}Cola rose")
+            .unwrap();
+        assert_eq!(results.get("LRLCY"), Some(&1));
+        assert_eq!(results.get("NSRGY"), Some(&1));
+        assert_eq!(results.get("KO"), Some(&1));
+
+        // Without diacritic folding, unaccented spellings do not match
+        assert!(ticker_sniffer.extract("Nestle rose").unwrap().is_empty());
+
+        let config = CompanyTokenProcessorConfig::builder()
+            .fold_diacritics(true)
+            .build()
+            .unwrap();
+        let ticker_sniffer =
+            TickerSniffer::with_company_symbol_list(&config, &company_symbol_list, true).unwrap();
+
+        let results = ticker_sniffer.extract("Nestle and L’Oreal rose").unwrap();
+        assert_eq!(results.get("NSRGY"), Some(&1));
+        assert_eq!(results.get("LRLCY"), Some(&1));
+    }
+
+    #[test]
+    fn test_normalized_mention_ranges() {
+        let ticker_sniffer = TickerSniffer::with_company_symbol_list(
+            DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG,
+            &get_company_symbol_list(),
+            true,
+        )
+        .unwrap();
+
+        // Ranges refer to the original text, including trailing combining marks
+        let text = "Shares of Nestle\u{301} rose";
+        let mentions = ticker_sniffer.extract_mentions(text).unwrap();
+
+        assert_eq!(mentions.len(), 1);
+        assert_eq!(&text[mentions[0].byte_range.clone()], "Nestle\u{301}");
+        assert_eq!(mentions[0].char_range, 10..17);
+    }
+
+    #[test]
+    fn test_full_width_ticker_symbols() {
+        let ticker_sniffer =
+            TickerSniffer::new(DEFAULT_COMPANY_TOKEN_PROCESSOR_CONFIG, true).unwrap();
+
+        let text = "ＩＮＴＣ and ＡＡＰＬ rose";
+        let mentions = ticker_sniffer.extract_mentions(text).unwrap();
+
+        let ticker_symbols: Vec<(&str, &str)> = mentions
+            .iter()
+            .map(|mention| (mention.ticker_symbol.as_str(), mention.text.as_str()))
+            .collect();
+        assert_eq!(
+            ticker_symbols,
+            vec![("INTC", "ＩＮＴＣ"), ("AAPL", "ＡＡＰＬ")]
+        );
+    }
+}